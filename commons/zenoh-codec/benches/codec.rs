@@ -85,6 +85,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         ext_qos: ext::QoSType::DEFAULT,
         ext_tstamp: None,
         ext_nodeid: ext::NodeIdType::DEFAULT,
+        ext_trace: None,
         payload: PushBody::Put(Put {
             timestamp: None,
             encoding: Encoding::empty(),
@@ -131,6 +132,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         ext_qos: ext::QoSType::DEFAULT,
         ext_tstamp: None,
         ext_nodeid: ext::NodeIdType::DEFAULT,
+        ext_trace: None,
         payload: PushBody::Put(Put {
             timestamp: None,
             encoding: Encoding::empty(),
@@ -172,6 +174,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         ext_qos: ext::QoSType::DEFAULT,
         ext_tstamp: None,
         ext_nodeid: ext::NodeIdType::DEFAULT,
+        ext_trace: None,
         payload: PushBody::Put(Put {
             timestamp: None,
             encoding: Encoding::empty(),
@@ -213,6 +216,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         ext_qos: ext::QoSType::DEFAULT,
         ext_tstamp: None,
         ext_nodeid: ext::NodeIdType::DEFAULT,
+        ext_trace: None,
         payload: PushBody::Put(Put {
             timestamp: None,
             encoding: Encoding::empty(),
@@ -241,6 +245,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         ext_qos: ext::QoSType::DEFAULT,
         ext_tstamp: None,
         ext_nodeid: ext::NodeIdType::DEFAULT,
+        ext_trace: None,
         payload: PushBody::Put(Put {
             timestamp: None,
             encoding: Encoding::empty(),
@@ -280,6 +285,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         ext_qos: ext::QoSType::DEFAULT,
         ext_tstamp: None,
         ext_nodeid: ext::NodeIdType::DEFAULT,
+        ext_trace: None,
         payload: PushBody::Put(Put {
             timestamp: None,
             encoding: Encoding::empty(),
@@ -18,6 +18,8 @@ mod push;
 mod request;
 mod response;
 
+use alloc::string::String;
+
 use zenoh_buffers::{
     reader::{DidntRead, Reader},
     writer::{DidntWrite, Writer},
@@ -26,13 +28,14 @@ use zenoh_protocol::{
     common::{imsg, ZExtZ64, ZExtZBufHeader},
     core::{EntityId, Reliability, ZenohIdProto},
     network::{
-        ext::{self, EntityGlobalIdType},
+        ext::{self, EntityGlobalIdType, TraceContextType},
         id, NetworkBody, NetworkBodyRef, NetworkMessage, NetworkMessageExt, NetworkMessageRef,
     },
 };
 
 use crate::{
-    LCodec, RCodec, WCodec, Zenoh080, Zenoh080Header, Zenoh080Length, Zenoh080Reliability,
+    LCodec, RCodec, WCodec, Zenoh080, Zenoh080Bounded, Zenoh080Header, Zenoh080Length,
+    Zenoh080Reliability,
 };
 
 // NetworkMessage
@@ -308,3 +311,64 @@ where
         Ok((ext::EntityGlobalIdType { zid, eid }, more))
     }
 }
+
+// Extension: TraceContext
+impl<const ID: u8> LCodec<&ext::TraceContextType<{ ID }>> for Zenoh080 {
+    fn w_len(self, x: &ext::TraceContextType<{ ID }>) -> usize {
+        let TraceContextType {
+            trace_id,
+            parent_id,
+            trace_flags: _,
+            tracestate,
+        } = x;
+
+        trace_id.len() + parent_id.len() + 1 + self.w_len(tracestate.as_str())
+    }
+}
+
+impl<W, const ID: u8> WCodec<(&ext::TraceContextType<{ ID }>, bool), &mut W> for Zenoh080
+where
+    W: Writer,
+{
+    type Output = Result<(), DidntWrite>;
+
+    fn write(self, writer: &mut W, x: (&ext::TraceContextType<{ ID }>, bool)) -> Self::Output {
+        let (x, more) = x;
+        let header: ZExtZBufHeader<{ ID }> = ZExtZBufHeader::new(self.w_len(x));
+        self.write(&mut *writer, (&header, more))?;
+
+        self.write(&mut *writer, &x.trace_id)?;
+        self.write(&mut *writer, &x.parent_id)?;
+        self.write(&mut *writer, x.trace_flags)?;
+        let zodec = Zenoh080Bounded::<u16>::new();
+        zodec.write(&mut *writer, x.tracestate.as_str())?;
+        Ok(())
+    }
+}
+
+impl<R, const ID: u8> RCodec<(ext::TraceContextType<{ ID }>, bool), &mut R> for Zenoh080Header
+where
+    R: Reader,
+{
+    type Error = DidntRead;
+
+    fn read(self, reader: &mut R) -> Result<(ext::TraceContextType<{ ID }>, bool), Self::Error> {
+        let (_, more): (ZExtZBufHeader<{ ID }>, bool) = self.read(&mut *reader)?;
+
+        let trace_id: [u8; 16] = self.codec.read(&mut *reader)?;
+        let parent_id: [u8; 8] = self.codec.read(&mut *reader)?;
+        let trace_flags: u8 = self.codec.read(&mut *reader)?;
+        let zodec = Zenoh080Bounded::<u16>::new();
+        let tracestate: String = zodec.read(&mut *reader)?;
+
+        Ok((
+            ext::TraceContextType {
+                trace_id,
+                parent_id,
+                trace_flags,
+                tracestate,
+            },
+            more,
+        ))
+    }
+}
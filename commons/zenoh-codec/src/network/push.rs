@@ -40,6 +40,7 @@ where
             ext_qos,
             ext_tstamp,
             ext_nodeid,
+            ext_trace,
             payload,
         } = x;
 
@@ -47,7 +48,8 @@ where
         let mut header = id::PUSH;
         let mut n_exts = ((ext_qos != &ext::QoSType::DEFAULT) as u8)
             + (ext_tstamp.is_some() as u8)
-            + ((ext_nodeid != &ext::NodeIdType::DEFAULT) as u8);
+            + ((ext_nodeid != &ext::NodeIdType::DEFAULT) as u8)
+            + (ext_trace.is_some() as u8);
         if n_exts != 0 {
             header |= flag::Z;
         }
@@ -75,6 +77,10 @@ where
             n_exts -= 1;
             self.write(&mut *writer, (*ext_nodeid, n_exts != 0))?;
         }
+        if let Some(tc) = ext_trace.as_ref() {
+            n_exts -= 1;
+            self.write(&mut *writer, (tc, n_exts != 0))?;
+        }
 
         // Payload
         self.write(&mut *writer, payload)?;
@@ -120,6 +126,7 @@ where
         let mut ext_qos = ext::QoSType::DEFAULT;
         let mut ext_tstamp = None;
         let mut ext_nodeid = ext::NodeIdType::DEFAULT;
+        let mut ext_trace = None;
 
         let mut has_ext = imsg::has_flag(self.header, flag::Z);
         while has_ext {
@@ -141,6 +148,11 @@ where
                     ext_nodeid = nid;
                     has_ext = ext;
                 }
+                ext::TraceContext::ID => {
+                    let (tc, ext): (ext::TraceContextType, bool) = eodec.read(&mut *reader)?;
+                    ext_trace = Some(tc);
+                    has_ext = ext;
+                }
                 _ => {
                     has_ext = extension::skip(reader, "Push", ext)?;
                 }
@@ -156,6 +168,7 @@ where
             ext_qos,
             ext_tstamp,
             ext_nodeid,
+            ext_trace,
         })
     }
 }
@@ -45,13 +45,15 @@ where
             ext_qos,
             ext_tstamp,
             ext_respid,
+            ext_trace,
         } = x;
 
         // Header
         let mut header = id::RESPONSE;
         let mut n_exts = ((ext_qos != &ext::QoSType::DEFAULT) as u8)
             + (ext_tstamp.is_some() as u8)
-            + (ext_respid.is_some() as u8);
+            + (ext_respid.is_some() as u8)
+            + (ext_trace.is_some() as u8);
         if n_exts != 0 {
             header |= flag::Z;
         }
@@ -80,6 +82,10 @@ where
             n_exts -= 1;
             self.write(&mut *writer, (ri, n_exts != 0))?;
         }
+        if let Some(tc) = ext_trace.as_ref() {
+            n_exts -= 1;
+            self.write(&mut *writer, (tc, n_exts != 0))?;
+        }
 
         // Payload
         self.write(&mut *writer, payload)?;
@@ -127,6 +133,7 @@ where
         let mut ext_qos = ext::QoSType::DEFAULT;
         let mut ext_tstamp = None;
         let mut ext_respid = None;
+        let mut ext_trace = None;
 
         let mut has_ext = imsg::has_flag(self.header, flag::Z);
         while has_ext {
@@ -148,6 +155,11 @@ where
                     ext_respid = Some(t);
                     has_ext = ext;
                 }
+                ext::TraceContext::ID => {
+                    let (tc, ext): (ext::TraceContextType, bool) = eodec.read(&mut *reader)?;
+                    ext_trace = Some(tc);
+                    has_ext = ext;
+                }
                 _ => {
                     has_ext = extension::skip(reader, "Response", ext)?;
                 }
@@ -164,6 +176,7 @@ where
             ext_qos,
             ext_tstamp,
             ext_respid,
+            ext_trace,
         })
     }
 }
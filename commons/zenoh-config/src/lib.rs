@@ -156,6 +156,63 @@ pub enum LowPassFilterMessage {
     Reply,
 }
 
+#[derive(Serialize, Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitConf {
+    /// Optional identifier for the rate limit configuration item
+    pub id: Option<String>,
+    /// A list of interfaces, transports using one of those interfaces will be rate limited.
+    /// Rate limits will be applied for all interfaces if the parameter is None
+    pub interfaces: Option<NEVec<Interface>>,
+    /// A list of certificate common names, transports authenticated with one of those will be rate limited.
+    /// Rate limits will be applied for all certificate common names if the parameter is None
+    pub cert_common_names: Option<NEVec<CertCommonName>>,
+    /// A list of usernames, transports authenticated with one of those will be rate limited.
+    /// Rate limits will be applied for all usernames if the parameter is None
+    pub usernames: Option<NEVec<Username>>,
+    /// A list of link types, transports having one of those link types will be rate limited.
+    /// Rate limits will be applied for all link types if the parameter is None
+    pub link_protocols: Option<NEVec<InterceptorLink>>,
+    /// Rate limit flow directions: egress and/or ingress
+    pub flows: Option<NEVec<InterceptorFlow>>,
+    /// List of message types on which the rate limits will be applied
+    pub messages: NEVec<RateLimitMessage>,
+    /// A list of key-expressions, each of them is given its own budgets
+    pub key_exprs: NEVec<OwnedKeyExpr>,
+    /// Maximum sustained rate in bytes per second (serialized payload + serialized attachment)
+    pub bytes_per_sec: Option<u64>,
+    /// Maximum burst in bytes (default: `bytes_per_sec`)
+    pub bytes_burst: Option<u64>,
+    /// Maximum sustained rate in messages per second
+    pub msgs_per_sec: Option<u64>,
+    /// Maximum burst in messages (default: `msgs_per_sec`)
+    pub msgs_burst: Option<u64>,
+    /// What to do with messages exceeding the budgets (default: `drop`)
+    pub policy: Option<RateLimitPolicy>,
+    /// Maximum delay in milliseconds of a message with the `delay` policy,
+    /// messages requiring a longer delay are dropped (default: 1000)
+    pub max_delay: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, Hash, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitMessage {
+    Put,
+    Delete,
+    Query,
+    Reply,
+}
+
+#[derive(Default, Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitPolicy {
+    /// Messages exceeding the budgets are dropped
+    #[default]
+    Drop,
+    /// Messages exceeding the budgets are delayed until the budgets are replenished
+    Delay,
+}
+
 #[derive(Serialize, Debug, Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct AclConfigRule {
@@ -741,6 +798,9 @@ validated_struct::validator! {
                 #[serde(default = "set_false")]
                 pub write: bool,
             },
+            /// The HTTP socket on which zenohd serves the metrics of the admin space in the OpenMetrics
+            /// text format (disabled by default): a port number or a `<local_ip>:<port_number>` string.
+            pub metrics_http_port: Option<String>,
 
         },
 
@@ -769,6 +829,16 @@ validated_struct::validator! {
         /// Configuration of the low-pass filter
         pub low_pass_filter: Vec<LowPassFilterConf>,
 
+        /// Configuration of the rate limits
+        pub rate_limit: Vec<RateLimitConf>,
+
+        /// Configuration of the per key expression statistics (requires the `stats` feature)
+        pub stats: #[derive(Default)]
+        StatsConf {
+            /// The key expressions for which dedicated message counters are maintained
+            pub key_exprs: Vec<OwnedKeyExpr>,
+        },
+
         /// A list of directories where plugins may be searched for if no `__path__` was specified for them.
         /// The executable's current directory will be added to the search paths.
         pub plugins_loading: #[derive(Default)]
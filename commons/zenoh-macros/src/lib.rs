@@ -567,6 +567,61 @@ pub fn register_param(input: proc_macro::TokenStream) -> proc_macro::TokenStream
         .into()
 }
 
+mod serialization_derive;
+use serialization_derive::{derive_deserialize, derive_serialize};
+
+/// Derive `zenoh_ext::Serialize` for a struct or an enum.
+///
+/// The generated implementation serializes the fields in declaration order, producing the same
+/// binary layout as calling `ZSerializer::serialize` on each field one after the other.
+/// Enums are serialized as the variant index (a `VarInt<usize>`) followed by the variant fields.
+///
+/// Fields can be annotated with:
+/// - `#[zenoh(skip)]`: the field is not serialized, and set to `Default::default()` when deserialized;
+/// - `#[zenoh(default)]`: the field is set to `Default::default()` if there is no data left when
+///   deserializing it. Only trailing fields can be annotated with it.
+///
+/// Structs annotated with `#[zenoh(tagged)]` are serialized with `ZTaggedSerializer`: each field
+/// is prefixed by its tag and its length, unknown fields are skipped and missing fields are set to
+/// `Default::default()` when deserialized. The tag of a field defaults to its position, and can be
+/// set with `#[zenoh(tag = 42)]`; explicit tags allow reordering or removing fields safely.
+/// ```rust,ignore
+/// #[derive(zenoh_ext::Serialize, zenoh_ext::Deserialize)]
+/// struct Message {
+///     id: u32,
+///     payload: Vec<u8>,
+///     #[zenoh(skip)]
+///     cached: Option<String>,
+/// }
+///
+/// #[derive(zenoh_ext::Serialize, zenoh_ext::Deserialize)]
+/// #[zenoh(tagged)]
+/// struct ExtensibleMessage {
+///     #[zenoh(tag = 0)]
+///     id: u32,
+///     #[zenoh(tag = 2)]
+///     timestamp: u64,
+/// }
+/// ```
+#[proc_macro_derive(Serialize, attributes(zenoh))]
+pub fn serialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: DeriveInput = syn::parse_macro_input!(input);
+    derive_serialize(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `zenoh_ext::Deserialize` for a struct or an enum.
+///
+/// See [`Serialize`](macro@Serialize) derive for the binary layout and the supported attributes.
+#[proc_macro_derive(Deserialize, attributes(zenoh))]
+pub fn deserialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: DeriveInput = syn::parse_macro_input!(input);
+    derive_deserialize(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// Macro `#[internal_trait]` should precede
 /// `impl Trait for Struct { ... }`
 ///
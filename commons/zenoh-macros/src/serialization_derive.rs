@@ -0,0 +1,391 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Fields, GenericParam, Generics,
+    Ident, Index, LitInt, Member,
+};
+
+/// Container attributes accepted in `#[zenoh(...)]`.
+#[derive(Default)]
+struct ContainerAttributes {
+    /// The struct fields are serialized with their tag and length, see `ZTaggedSerializer`.
+    tagged: bool,
+}
+
+impl ContainerAttributes {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("zenoh")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tagged") {
+                    res.tagged = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported zenoh attribute, expected `tagged`"))
+                }
+            })?;
+        }
+        Ok(res)
+    }
+}
+
+/// Field attributes accepted in `#[zenoh(...)]`.
+#[derive(Default)]
+struct FieldAttributes {
+    /// The field is not serialized, and is initialized with `Default::default()` when deserialized.
+    skip: bool,
+    /// The field is initialized with `Default::default()` if there is no data left to deserialize.
+    default: bool,
+    /// The tag of the field in a tagged struct.
+    tag: Option<LitInt>,
+}
+
+impl FieldAttributes {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("zenoh")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    res.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    res.default = true;
+                    Ok(())
+                } else if meta.path.is_ident("tag") {
+                    res.tag = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta
+                        .error("unsupported zenoh attribute, expected `skip`, `default` or `tag`"))
+                }
+            })?;
+        }
+        if res.skip && res.default {
+            return Err(syn::Error::new(
+                attrs[0].span(),
+                "`skip` and `default` zenoh attributes are mutually exclusive",
+            ));
+        }
+        Ok(res)
+    }
+}
+
+struct Field {
+    member: Member,
+    binding: Ident,
+    attrs: FieldAttributes,
+    tag: usize,
+}
+
+fn parse_fields(fields: &Fields, tagged: bool) -> syn::Result<Vec<Field>> {
+    let mut res: Vec<Field> = Vec::with_capacity(fields.len());
+    let mut default_seen = false;
+    for (i, field) in fields.iter().enumerate() {
+        let attrs = FieldAttributes::parse(&field.attrs)?;
+        let tag = match (&attrs.tag, tagged) {
+            (Some(tag), true) => tag.base10_parse()?,
+            (None, true) => i,
+            (Some(tag), false) => {
+                return Err(syn::Error::new(
+                    tag.span(),
+                    "`tag` zenoh attribute requires `#[zenoh(tagged)]` on the struct",
+                ))
+            }
+            (None, false) => 0,
+        };
+        if tagged && attrs.default {
+            return Err(syn::Error::new(
+                field.span(),
+                "`default` zenoh attribute is implied for all fields of a tagged struct",
+            ));
+        }
+        if tagged && !attrs.skip && res.iter().any(|f| !f.attrs.skip && f.tag == tag) {
+            return Err(syn::Error::new(field.span(), "duplicated zenoh tag"));
+        }
+        if attrs.default {
+            default_seen = true;
+        } else if default_seen && !attrs.skip {
+            return Err(syn::Error::new(
+                field.span(),
+                "fields following a `#[zenoh(default)]` field must also be `default` or `skip`",
+            ));
+        }
+        let (member, binding) = match &field.ident {
+            Some(ident) => (Member::Named(ident.clone()), ident.clone()),
+            None => (
+                Member::Unnamed(Index {
+                    index: i as u32,
+                    span: field.span(),
+                }),
+                format_ident!("__field{}", i),
+            ),
+        };
+        res.push(Field {
+            member,
+            binding,
+            attrs,
+            tag,
+        });
+    }
+    Ok(res)
+}
+
+fn add_trait_bound(generics: &Generics, bound: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    for param in &mut generics.params {
+        if let GenericParam::Type(ty) = param {
+            ty.bounds.push(parse_quote!(#bound));
+        }
+    }
+    generics
+}
+
+/// Generate the serialization of fields already bound to local variables.
+fn serialize_fields(fields: &[Field]) -> TokenStream {
+    let bindings = fields.iter().filter(|f| !f.attrs.skip).map(|f| &f.binding);
+    quote! {
+        #(zenoh_ext::Serialize::serialize(#bindings, serializer);)*
+    }
+}
+
+/// Generate the serialization of fields already bound to local variables, with their tag.
+fn serialize_tagged_fields(fields: &[Field]) -> TokenStream {
+    let (tags, bindings): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter(|f| !f.attrs.skip)
+        .map(|f| (f.tag, &f.binding))
+        .unzip();
+    let mutability = (!tags.is_empty()).then(|| quote!(mut));
+    quote! {
+        let #mutability __fields = zenoh_ext::ZTaggedSerializer::new();
+        #(__fields.serialize_field(#tags, #bindings);)*
+        zenoh_ext::Serialize::serialize(&__fields, serializer);
+    }
+}
+
+/// Generate the deserialization of tagged fields into local variables.
+fn deserialize_tagged_fields(fields: &[Field]) -> TokenStream {
+    let stmts = fields.iter().map(|f| {
+        let binding = &f.binding;
+        let tag = f.tag;
+        if f.attrs.skip {
+            quote!(let #binding = ::core::default::Default::default();)
+        } else {
+            quote!(let #binding = __fields.deserialize_field_or_default(#tag)?;)
+        }
+    });
+    let mutability = fields.iter().any(|f| !f.attrs.skip).then(|| quote!(mut));
+    quote! {
+        let #mutability __fields: zenoh_ext::ZTaggedDeserializer =
+            zenoh_ext::Deserialize::deserialize(deserializer)?;
+        #(#stmts)*
+    }
+}
+
+/// Generate the deserialization of fields into local variables.
+fn deserialize_fields(fields: &[Field]) -> TokenStream {
+    let stmts = fields.iter().map(|f| {
+        let binding = &f.binding;
+        if f.attrs.skip {
+            quote!(let #binding = ::core::default::Default::default();)
+        } else if f.attrs.default {
+            quote! {
+                let #binding = if deserializer.done() {
+                    ::core::default::Default::default()
+                } else {
+                    zenoh_ext::Deserialize::deserialize(deserializer)?
+                };
+            }
+        } else {
+            quote!(let #binding = zenoh_ext::Deserialize::deserialize(deserializer)?;)
+        }
+    });
+    quote!(#(#stmts)*)
+}
+
+/// Generate the pattern destructuring (or the expression constructing) the given fields.
+///
+/// When `wildcard_skipped` is set, skipped fields are matched with `_` instead of being bound.
+fn fields_pattern(
+    path: TokenStream,
+    fields: &Fields,
+    parsed: &[Field],
+    wildcard_skipped: bool,
+) -> TokenStream {
+    let entries = parsed.iter().map(|f| {
+        let binding = &f.binding;
+        match (&f.member, wildcard_skipped && f.attrs.skip) {
+            (member, true) => quote!(#member: _),
+            (Member::Named(_), false) => quote!(#binding),
+            (member, false) => quote!(#member: #binding),
+        }
+    });
+    match fields {
+        Fields::Named(_) | Fields::Unnamed(_) => quote!(#path { #(#entries),* }),
+        Fields::Unit => path,
+    }
+}
+
+fn check_tagged(input: &DeriveInput) -> syn::Result<bool> {
+    let attrs = ContainerAttributes::parse(&input.attrs)?;
+    if attrs.tagged && !matches!(input.data, Data::Struct(_)) {
+        return Err(syn::Error::new(
+            input.span(),
+            "`tagged` zenoh attribute is only supported on structs",
+        ));
+    }
+    Ok(attrs.tagged)
+}
+
+pub(crate) fn derive_serialize(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let tagged = check_tagged(&input)?;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = parse_fields(&data.fields, tagged)?;
+            let pattern = fields_pattern(quote!(Self), &data.fields, &fields, true);
+            let serialize = if tagged {
+                serialize_tagged_fields(&fields)
+            } else {
+                serialize_fields(&fields)
+            };
+            quote! {
+                let #pattern = self;
+                #serialize
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    let variant_ident = &variant.ident;
+                    let fields = parse_fields(&variant.fields, false)?;
+                    let pattern = fields_pattern(
+                        quote!(Self::#variant_ident),
+                        &variant.fields,
+                        &fields,
+                        true,
+                    );
+                    let serialize = serialize_fields(&fields);
+                    Ok(quote! {
+                        #pattern => {
+                            zenoh_ext::Serialize::serialize(
+                                &zenoh_ext::__private::VarInt::<usize>(#i),
+                                serializer,
+                            );
+                            #serialize
+                        }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            if arms.is_empty() {
+                quote!(match *self {})
+            } else {
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "zenoh_ext::Serialize cannot be derived for unions",
+            ))
+        }
+    };
+    let generics = add_trait_bound(&input.generics, quote!(zenoh_ext::Serialize));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics zenoh_ext::Serialize for #ident #ty_generics #where_clause {
+            fn serialize(&self, serializer: &mut zenoh_ext::ZSerializer) {
+                #body
+            }
+        }
+    })
+}
+
+pub(crate) fn derive_deserialize(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let tagged = check_tagged(&input)?;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = parse_fields(&data.fields, tagged)?;
+            let deserialize = if tagged {
+                deserialize_tagged_fields(&fields)
+            } else {
+                deserialize_fields(&fields)
+            };
+            let construct = fields_pattern(quote!(Self), &data.fields, &fields, false);
+            quote! {
+                #deserialize
+                Ok(#construct)
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    let variant_ident = &variant.ident;
+                    let fields = parse_fields(&variant.fields, false)?;
+                    let deserialize = deserialize_fields(&fields);
+                    let construct = fields_pattern(
+                        quote!(Self::#variant_ident),
+                        &variant.fields,
+                        &fields,
+                        false,
+                    );
+                    Ok(quote! {
+                        #i => {
+                            #deserialize
+                            Ok(#construct)
+                        }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! {
+                let zenoh_ext::__private::VarInt::<usize>(discriminant) =
+                    zenoh_ext::Deserialize::deserialize(deserializer)?;
+                match discriminant {
+                    #(#arms)*
+                    _ => Err(zenoh_ext::ZDeserializeError),
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "zenoh_ext::Deserialize cannot be derived for unions",
+            ))
+        }
+    };
+    let generics = add_trait_bound(&input.generics, quote!(zenoh_ext::Deserialize));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics zenoh_ext::Deserialize for #ident #ty_generics #where_clause {
+            fn deserialize(
+                deserializer: &mut zenoh_ext::ZDeserializer,
+            ) -> ::core::result::Result<Self, zenoh_ext::ZDeserializeError> {
+                #body
+            }
+        }
+    })
+}
@@ -379,6 +379,7 @@ impl From<ResponseFinal> for NetworkMessage {
 
 // Extensions
 pub mod ext {
+    use alloc::string::String;
     use core::fmt;
 
     use crate::{
@@ -616,4 +617,53 @@ pub mod ext {
             Self { zid, eid }
         }
     }
+
+    /// ```text
+    ///  7 6 5 4 3 2 1 0
+    /// +-+-+-+-+-+-+-+-+
+    /// |Z|1_0|    ID   |
+    /// +-+-+-+---------+
+    /// ~   trace_id    ~  -- 16 bytes
+    /// +---------------+
+    /// ~   parent_id   ~  -- 8 bytes
+    /// +---------------+
+    /// |  trace_flags  |
+    /// +---------------+
+    /// ~  tracestate   ~  -- <u8;z16>
+    /// +---------------+
+    /// ```
+    ///
+    /// W3C trace context (`traceparent` and `tracestate` headers) of the message.
+    /// See <https://www.w3.org/TR/trace-context/>.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct TraceContextType<const ID: u8> {
+        pub trace_id: [u8; 16],
+        pub parent_id: [u8; 8],
+        pub trace_flags: u8,
+        pub tracestate: String,
+    }
+
+    impl<const ID: u8> TraceContextType<{ ID }> {
+        #[cfg(feature = "test")]
+        pub fn rand() -> Self {
+            use rand::{distributions::Alphanumeric, Rng};
+            let mut rng = rand::thread_rng();
+
+            let trace_id = rng.gen();
+            let parent_id = rng.gen();
+            let trace_flags = rng.gen();
+            let len = rng.gen_range(0..16);
+            let tracestate = (&mut rng)
+                .sample_iter(Alphanumeric)
+                .take(len)
+                .map(char::from)
+                .collect();
+            Self {
+                trace_id,
+                parent_id,
+                trace_flags,
+                tracestate,
+            }
+        }
+    }
 }
@@ -44,6 +44,7 @@ pub struct Push {
     pub ext_qos: ext::QoSType,
     pub ext_tstamp: Option<ext::TimestampType>,
     pub ext_nodeid: ext::NodeIdType,
+    pub ext_trace: Option<ext::TraceContextType>,
     pub payload: PushBody,
 }
 
@@ -61,6 +62,9 @@ pub mod ext {
 
     pub type NodeId = zextz64!(0x3, true);
     pub type NodeIdType = crate::network::ext::NodeIdType<{ NodeId::ID }>;
+
+    pub type TraceContext = zextzbuf!(0x4, false);
+    pub type TraceContextType = crate::network::ext::TraceContextType<{ TraceContext::ID }>;
 }
 
 impl Push {
@@ -74,6 +78,7 @@ impl Push {
         let ext_qos = ext::QoSType::rand();
         let ext_tstamp = rng.gen_bool(0.5).then(ext::TimestampType::rand);
         let ext_nodeid = ext::NodeIdType::rand();
+        let ext_trace = rng.gen_bool(0.5).then(ext::TraceContextType::rand);
 
         Self {
             wire_expr,
@@ -81,6 +86,7 @@ impl Push {
             ext_tstamp,
             ext_qos,
             ext_nodeid,
+            ext_trace,
         }
     }
 }
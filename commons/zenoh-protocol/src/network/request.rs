@@ -61,6 +61,7 @@ pub struct Request {
     pub ext_target: ext::QueryTarget,
     pub ext_budget: Option<ext::BudgetType>,
     pub ext_timeout: Option<ext::TimeoutType>,
+    pub ext_trace: Option<ext::TraceContextType>,
     pub payload: RequestBody,
 }
 
@@ -129,6 +130,9 @@ pub mod ext {
     // The timeout of the request
     pub type Timeout = zextz64!(0x6, false);
     pub type TimeoutType = Duration;
+
+    pub type TraceContext = zextzbuf!(0x7, false);
+    pub type TraceContextType = crate::network::ext::TraceContextType<{ TraceContext::ID }>;
 }
 
 impl Request {
@@ -156,6 +160,7 @@ impl Request {
         } else {
             None
         };
+        let ext_trace = rng.gen_bool(0.5).then(ext::TraceContextType::rand);
 
         Self {
             wire_expr,
@@ -167,6 +172,7 @@ impl Request {
             ext_target,
             ext_budget,
             ext_timeout,
+            ext_trace,
         }
     }
 }
@@ -53,6 +53,7 @@ pub struct Response {
     pub ext_qos: ext::QoSType,
     pub ext_tstamp: Option<ext::TimestampType>,
     pub ext_respid: Option<ext::ResponderIdType>,
+    pub ext_trace: Option<ext::TraceContextType>,
 }
 
 pub mod ext {
@@ -68,6 +69,9 @@ pub mod ext {
 
     pub type ResponderId = zextzbuf!(0x3, false);
     pub type ResponderIdType = crate::network::ext::EntityGlobalIdType<{ ResponderId::ID }>;
+
+    pub type TraceContext = zextzbuf!(0x4, false);
+    pub type TraceContextType = crate::network::ext::TraceContextType<{ TraceContext::ID }>;
 }
 
 impl Response {
@@ -82,6 +86,7 @@ impl Response {
         let ext_qos = ext::QoSType::rand();
         let ext_tstamp = rng.gen_bool(0.5).then(ext::TimestampType::rand);
         let ext_respid = rng.gen_bool(0.5).then(ext::ResponderIdType::rand);
+        let ext_trace = rng.gen_bool(0.5).then(ext::TraceContextType::rand);
 
         Self {
             rid,
@@ -90,6 +95,7 @@ impl Response {
             ext_qos,
             ext_tstamp,
             ext_respid,
+            ext_trace,
         }
     }
 }
@@ -612,6 +612,7 @@ mod tests {
             ext_qos: ext::QoSType::new(Priority::DEFAULT, CongestionControl::Block, false),
             ext_tstamp: None,
             ext_nodeid: ext::NodeIdType::DEFAULT,
+            ext_trace: None,
             payload: PushBody::Put(Put {
                 timestamp: None,
                 encoding: Encoding::empty(),
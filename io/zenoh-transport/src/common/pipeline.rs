@@ -1052,6 +1052,7 @@ mod tests {
                 ext_qos: ext::QoSType::new(Priority::Control, CongestionControl::Block, false),
                 ext_tstamp: None,
                 ext_nodeid: ext::NodeIdType::DEFAULT,
+                ext_trace: None,
                 payload: PushBody::Put(Put {
                     timestamp: None,
                     encoding: Encoding::empty(),
@@ -1182,6 +1183,7 @@ mod tests {
                 ext_qos: ext::QoSType::new(Priority::Control, CongestionControl::Block, false),
                 ext_tstamp: None,
                 ext_nodeid: ext::NodeIdType::DEFAULT,
+                ext_trace: None,
                 payload: PushBody::Put(Put {
                     timestamp: None,
                     encoding: Encoding::empty(),
@@ -1300,6 +1302,7 @@ mod tests {
                         ),
                         ext_tstamp: None,
                         ext_nodeid: ext::NodeIdType::DEFAULT,
+                        ext_trace: None,
                         payload: PushBody::Put(Put {
                             timestamp: None,
                             encoding: Encoding::empty(),
@@ -1359,6 +1362,7 @@ mod tests {
             ext_qos: ext::QoSType::new(Priority::Control, CongestionControl::Block, true),
             ext_tstamp: None,
             ext_nodeid: ext::NodeIdType::DEFAULT,
+            ext_trace: None,
             payload: PushBody::Put(Put {
                 timestamp: None,
                 encoding: Encoding::empty(),
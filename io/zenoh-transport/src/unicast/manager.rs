@@ -566,7 +566,14 @@ impl TransportManager {
         let is_multilink = zcondfeat!("transport_multilink", config.multilink.is_some(), false);
 
         #[cfg(feature = "stats")]
-        let mut labels = HashMap::from([("zid".to_string(), config.zid.to_string())]);
+        let mut labels = HashMap::from([
+            ("zid".to_string(), config.zid.to_string()),
+            ("whatami".to_string(), config.whatami.to_string()),
+            (
+                "protocol".to_string(),
+                link.link.link.get_dst().protocol().to_string(),
+            ),
+        ]);
         #[cfg(feature = "stats")]
         if let Some(cert_common_name) = link.link.link.get_auth_id().get_cert_common_name() {
             labels.insert("cert_common_name".to_owned(), cert_common_name.to_owned());
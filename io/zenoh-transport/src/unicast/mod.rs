@@ -130,8 +130,11 @@ impl TransportUnicast {
         Ok(transport.get_auth_ids())
     }
 
+    /// Schedules a message for transmission.
+    ///
+    /// Returns `false` if the message was dropped due to congestion.
     #[inline(always)]
-    pub fn schedule(&self, message: NetworkMessageMut) -> ZResult<()> {
+    pub fn schedule(&self, message: NetworkMessageMut) -> ZResult<bool> {
         let transport = self.get_inner()?;
         transport.schedule(message)
     }
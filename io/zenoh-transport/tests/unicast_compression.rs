@@ -289,6 +289,7 @@ mod tests {
             ext_qos: QoSType::new(channel.priority, cctrl, false),
             ext_tstamp: None,
             ext_nodeid: NodeIdType::DEFAULT,
+            ext_trace: None,
             payload: Put {
                 payload: vec![0u8; msg_size].into(),
                 timestamp: None,
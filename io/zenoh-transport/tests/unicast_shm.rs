@@ -247,6 +247,7 @@ mod tests {
                 ext_qos: QoSType::new(Priority::DEFAULT, CongestionControl::Block, false),
                 ext_tstamp: None,
                 ext_nodeid: NodeIdType::DEFAULT,
+                ext_trace: None,
                 payload: Put {
                     payload: sbuf.into(),
                     timestamp: None,
@@ -288,6 +289,7 @@ mod tests {
                 ext_qos: QoSType::new(Priority::DEFAULT, CongestionControl::Block, false),
                 ext_tstamp: None,
                 ext_nodeid: NodeIdType::DEFAULT,
+                ext_trace: None,
                 payload: Put {
                     payload: sbuf.into(),
                     timestamp: None,
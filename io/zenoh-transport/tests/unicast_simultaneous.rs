@@ -76,6 +76,7 @@ mod tests {
                 ext_qos: QoSType::new(Priority::Control, CongestionControl::Block, false),
                 ext_tstamp: None,
                 ext_nodeid: NodeIdType::DEFAULT,
+                ext_trace: None,
                 payload: Put {
                     payload: vec![0u8; MSG_SIZE].into(),
                     timestamp: None,
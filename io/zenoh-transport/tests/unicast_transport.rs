@@ -485,6 +485,7 @@ async fn test_transport(
         ext_qos: QoSType::new(channel.priority, cctrl, false),
         ext_tstamp: None,
         ext_nodeid: NodeIdType::DEFAULT,
+        ext_trace: None,
         payload: Put {
             payload: vec![0u8; msg_size].into(),
             timestamp: None,
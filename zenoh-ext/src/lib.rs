@@ -33,10 +33,18 @@ mod subscriber_ext;
 
 #[cfg(feature = "internal")]
 pub use crate::serialization::VarInt;
+pub use zenoh_macros::{Deserialize, Serialize};
+
 pub use crate::serialization::{
     z_deserialize, z_serialize, Deserialize, Serialize, ZDeserializeError, ZDeserializer,
-    ZReadIter, ZSerializer,
+    ZReadIter, ZSerializer, ZTaggedDeserializer, ZTaggedSerializer,
 };
+
+/// Items used by the code generated by `Serialize`/`Deserialize` derive macros.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::serialization::VarInt;
+}
 #[cfg(feature = "unstable")]
 #[allow(deprecated)]
 pub use crate::{
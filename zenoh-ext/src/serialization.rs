@@ -545,6 +545,103 @@ impl_tuple!(
     T15 / 15,
 );
 
+/// Tagged fields serializer, used to serialize extensible structures.
+///
+/// Each field is serialized with its tag and its length, so a [`ZTaggedDeserializer`] can skip
+/// the fields it doesn't know, and default the ones which are missing. It allows publishers and
+/// subscribers built with different versions of a structure to interoperate, as long as tags
+/// are never reused for a different field.
+///
+/// Tagged fields are serialized as a sequence of `(tag, bytes)` pairs, i.e. with the same
+/// layout as `Vec<(VarInt<usize>, ZBytes)>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use zenoh_ext::*;
+/// let mut fields = ZTaggedSerializer::new();
+/// fields.serialize_field(0, 42u32);
+/// fields.serialize_field(1, "hello");
+/// let zbytes = z_serialize(&fields);
+///
+/// let mut fields = z_deserialize::<ZTaggedDeserializer>(&zbytes).unwrap();
+/// assert_eq!(fields.deserialize_field::<u32>(0).unwrap(), Some(42));
+/// // unknown fields are ignored, missing fields are returned as `None`
+/// assert_eq!(fields.deserialize_field::<f64>(2).unwrap(), None);
+/// ```
+#[derive(Debug, Default)]
+pub struct ZTaggedSerializer {
+    fields: Vec<(usize, ZBytes)>,
+}
+
+impl ZTaggedSerializer {
+    /// Instantiate an empty [`ZTaggedSerializer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serialize the given field with its tag.
+    ///
+    /// Fields are serialized in the order they are added.
+    pub fn serialize_field<T: Serialize>(&mut self, tag: usize, t: T) {
+        let mut serializer = ZSerializer::new();
+        serializer.serialize(t);
+        self.fields.push((tag, serializer.finish()));
+    }
+}
+
+impl Serialize for ZTaggedSerializer {
+    fn serialize(&self, serializer: &mut ZSerializer) {
+        serializer.serialize_iter(self.fields.iter().map(|(tag, field)| (VarInt(*tag), field)));
+    }
+}
+
+/// Tagged fields deserializer, used to deserialize extensible structures.
+///
+/// See [`ZTaggedSerializer`] for the binary format.
+#[derive(Debug, Default)]
+pub struct ZTaggedDeserializer {
+    fields: Vec<(usize, Vec<u8>)>,
+}
+
+impl ZTaggedDeserializer {
+    /// Deserialize the field with the given tag, or return `None` if the field is missing.
+    ///
+    /// The whole field content must be consumed by the deserialization.
+    pub fn deserialize_field<T: Deserialize>(
+        &mut self,
+        tag: usize,
+    ) -> Result<Option<T>, ZDeserializeError> {
+        match self.fields.iter().position(|(t, _)| *t == tag) {
+            Some(i) => z_deserialize(&self.fields.swap_remove(i).1.into()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Deserialize the field with the given tag, or return `T::default()` if the field is missing.
+    pub fn deserialize_field_or_default<T: Deserialize + Default>(
+        &mut self,
+        tag: usize,
+    ) -> Result<T, ZDeserializeError> {
+        Ok(self.deserialize_field(tag)?.unwrap_or_default())
+    }
+}
+
+impl Deserialize for ZTaggedDeserializer {
+    fn deserialize(deserializer: &mut ZDeserializer) -> Result<Self, ZDeserializeError> {
+        let mut fields = Vec::new();
+        // `Vec<u8>` has the same layout as a serialized `ZBytes`
+        for field in deserializer.deserialize_iter::<(VarInt<usize>, Vec<u8>)>()? {
+            let (VarInt(tag), field) = field?;
+            if fields.iter().any(|(t, _)| *t == tag) {
+                return Err(ZDeserializeError);
+            }
+            fields.push((tag, field));
+        }
+        Ok(Self { fields })
+    }
+}
+
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct VarInt<T>(pub T);
@@ -671,4 +768,27 @@ mod tests {
         let vp: Vec<(&str, i16)> = vec![("s1", 10), ("s2", -10000)];
         check_binary_format!(vp, vec![2, 2, 115, 49, 10, 0, 2, 115, 50, 240, 216]);
     }
+
+    #[test]
+    fn tagged_serialization() {
+        let mut fields = ZTaggedSerializer::new();
+        fields.serialize_field(0, 42u8);
+        fields.serialize_field(3, "test");
+        check_binary_format!(fields, vec![2, 0, 1, 42, 3, 5, 4, 116, 101, 115, 116]);
+        let payload = z_serialize(&fields);
+        let mut fields = z_deserialize::<ZTaggedDeserializer>(&payload).unwrap();
+        assert_eq!(fields.deserialize_field::<u8>(1).unwrap(), None);
+        assert_eq!(
+            fields.deserialize_field::<String>(3).unwrap().unwrap(),
+            "test"
+        );
+        assert_eq!(fields.deserialize_field_or_default::<u32>(4).unwrap(), 0);
+        // field must be entirely consumed
+        assert!(fields.deserialize_field::<()>(0).is_err());
+        // duplicated tags are rejected
+        let mut fields = ZTaggedSerializer::new();
+        fields.serialize_field(0, 42u8);
+        fields.serialize_field(0, 43u8);
+        assert!(z_deserialize::<ZTaggedDeserializer>(&z_serialize(&fields)).is_err());
+    }
 }
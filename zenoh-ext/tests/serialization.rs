@@ -0,0 +1,213 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::collections::HashMap;
+
+use zenoh_ext::{z_deserialize, z_serialize, Deserialize, Serialize, ZSerializer};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Named {
+    a: u16,
+    b: f32,
+    c: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Tuple(u16, f32, String);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Unit;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Generic<T, U> {
+    t: T,
+    u: Vec<U>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Attributes {
+    id: u32,
+    #[zenoh(skip)]
+    cached: Option<String>,
+    #[zenoh(default)]
+    extra: HashMap<String, String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Enum {
+    Unit,
+    Tuple(u8, String),
+    Named { x: i64, y: Vec<u8> },
+}
+
+macro_rules! serialize_deserialize {
+    ($ty:ty, $expr:expr) => {
+        let expr: &$ty = &$expr;
+        let payload = z_serialize(expr);
+        let output = z_deserialize::<$ty>(&payload).unwrap();
+        assert_eq!(*expr, output);
+    };
+}
+
+#[test]
+fn derive_struct_layout() {
+    let named = Named {
+        a: 500,
+        b: 1234.0,
+        c: "test".into(),
+    };
+    let tuple = Tuple(500, 1234.0, "test".into());
+    let expected = z_serialize(&(500u16, 1234.0f32, "test"))
+        .to_bytes()
+        .into_owned();
+    assert_eq!(z_serialize(&named).to_bytes(), expected);
+    assert_eq!(z_serialize(&tuple).to_bytes(), expected);
+    assert!(z_serialize(&Unit).is_empty());
+    serialize_deserialize!(Named, named);
+    serialize_deserialize!(Tuple, tuple);
+    serialize_deserialize!(Unit, Unit);
+}
+
+#[test]
+fn derive_generic() {
+    serialize_deserialize!(
+        Generic<String, (u8, bool)>,
+        Generic {
+            t: "generic".to_string(),
+            u: vec![(1, true), (2, false)],
+        }
+    );
+}
+
+#[test]
+fn derive_enum_layout() {
+    assert_eq!(z_serialize(&Enum::Unit).to_bytes(), vec![0]);
+    assert_eq!(
+        z_serialize(&Enum::Tuple(42, "s".into())).to_bytes(),
+        vec![1, 42, 1, 115]
+    );
+    assert_eq!(
+        z_serialize(&Enum::Named {
+            x: -1,
+            y: vec![1, 2]
+        })
+        .to_bytes(),
+        vec![2, 255, 255, 255, 255, 255, 255, 255, 255, 2, 1, 2]
+    );
+    serialize_deserialize!(Enum, Enum::Unit);
+    serialize_deserialize!(Enum, Enum::Tuple(42, "s".into()));
+    serialize_deserialize!(
+        Enum,
+        Enum::Named {
+            x: i64::MIN,
+            y: vec![]
+        }
+    );
+    assert!(z_deserialize::<Enum>(&z_serialize(&3u8)).is_err());
+}
+
+#[test]
+fn derive_field_attributes() {
+    let value = Attributes {
+        id: 42,
+        cached: Some("not serialized".into()),
+        extra: HashMap::from([("key".into(), "value".into())]),
+    };
+    let payload = z_serialize(&value);
+    assert_eq!(
+        payload.to_bytes(),
+        z_serialize(&(42u32, &value.extra)).to_bytes()
+    );
+    let output = z_deserialize::<Attributes>(&payload).unwrap();
+    assert_eq!(output.id, 42);
+    assert_eq!(output.cached, None);
+    assert_eq!(output.extra, value.extra);
+    // payload produced before `extra` field was added
+    let mut serializer = ZSerializer::new();
+    serializer.serialize(42u32);
+    let output = z_deserialize::<Attributes>(&serializer.finish()).unwrap();
+    assert_eq!(
+        output,
+        Attributes {
+            id: 42,
+            cached: None,
+            extra: HashMap::new(),
+        }
+    );
+}
+
+mod v1 {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[zenoh(tagged)]
+    pub struct Message {
+        #[zenoh(tag = 0)]
+        pub id: u32,
+        #[zenoh(tag = 1)]
+        pub payload: Vec<u8>,
+        #[zenoh(tag = 2)]
+        pub deprecated: String,
+    }
+}
+
+mod v2 {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[zenoh(tagged)]
+    pub struct Message {
+        #[zenoh(tag = 3)]
+        pub timestamp: u64,
+        #[zenoh(tag = 1)]
+        pub payload: Vec<u8>,
+        #[zenoh(tag = 0)]
+        pub id: u32,
+        #[zenoh(skip)]
+        pub local: bool,
+    }
+}
+
+#[test]
+fn derive_tagged_evolution() {
+    let old = v1::Message {
+        id: 42,
+        payload: vec![1, 2, 3],
+        deprecated: "unused".into(),
+    };
+    let new = v2::Message {
+        timestamp: 1000,
+        payload: vec![4, 5],
+        id: 43,
+        local: true,
+    };
+    serialize_deserialize!(v1::Message, old);
+    // unknown fields are skipped, missing fields are defaulted
+    assert_eq!(
+        z_deserialize::<v2::Message>(&z_serialize(&old)).unwrap(),
+        v2::Message {
+            timestamp: 0,
+            payload: vec![1, 2, 3],
+            id: 42,
+            local: false,
+        }
+    );
+    assert_eq!(
+        z_deserialize::<v1::Message>(&z_serialize(&new)).unwrap(),
+        v1::Message {
+            id: 43,
+            payload: vec![4, 5],
+            deprecated: String::new(),
+        }
+    );
+}
@@ -24,6 +24,8 @@ use zenoh_result::ZResult;
 
 #[zenoh_macros::unstable]
 use crate::api::sample::SourceInfo;
+#[cfg(feature = "trace-context")]
+use crate::api::sample::TraceContext;
 use crate::api::{
     builders::sample::{
         EncodingBuilderTrait, QoSBuilderTrait, SampleBuilder, SampleBuilderTrait,
@@ -252,6 +254,10 @@ impl Resolvable for ReplyErrBuilder<'_> {
 
 impl Wait for ReplyErrBuilder<'_> {
     fn wait(self) -> <Self as Resolvable>::To {
+        #[cfg(not(feature = "trace-context"))]
+        let ext_trace = None;
+        #[cfg(feature = "trace-context")]
+        let ext_trace = TraceContext::current().map(Into::into);
         self.query.inner.primitives.send_response(&mut Response {
             rid: self.query.inner.qid,
             wire_expr: WireExpr {
@@ -273,6 +279,7 @@ impl Wait for ReplyErrBuilder<'_> {
                 zid: self.query.inner.zid,
                 eid: self.query.eid,
             }),
+            ext_trace,
         });
         Ok(())
     }
@@ -96,6 +96,8 @@ impl SampleBuilder<SampleBuilderPut> {
                 #[cfg(feature = "unstable")]
                 source_info: SourceInfo::empty(),
                 attachment: None,
+                #[cfg(feature = "trace-context")]
+                trace_context: None,
             },
             _t: PhantomData::<SampleBuilderPut>,
         }
@@ -128,6 +130,8 @@ impl SampleBuilder<SampleBuilderDelete> {
                 #[cfg(feature = "unstable")]
                 source_info: SourceInfo::empty(),
                 attachment: None,
+                #[cfg(feature = "trace-context")]
+                trace_context: None,
             },
             _t: PhantomData::<SampleBuilderDelete>,
         }
@@ -308,6 +312,8 @@ impl From<&PublicationBuilder<&Publisher<'_>, PublicationBuilderPut>> for Sample
             #[cfg(feature = "unstable")]
             source_info: builder.source_info.clone(),
             attachment: builder.attachment.clone(),
+            #[cfg(feature = "trace-context")]
+            trace_context: None,
         }
     }
 }
@@ -331,6 +337,8 @@ impl From<&PublicationBuilder<&Publisher<'_>, PublicationBuilderDelete>> for Sam
             #[cfg(feature = "unstable")]
             source_info: builder.source_info.clone(),
             attachment: builder.attachment.clone(),
+            #[cfg(feature = "trace-context")]
+            trace_context: None,
         }
     }
 }
@@ -27,6 +27,8 @@ pub use zenoh_protocol::network::request::ext::QueryTarget;
 #[doc(inline)]
 pub use zenoh_protocol::zenoh::query::ConsolidationMode;
 
+#[cfg(feature = "trace-context")]
+use crate::api::sample::TraceContext;
 use crate::api::{
     bytes::ZBytes, encoding::Encoding, handlers::Callback, key_expr::KeyExpr, sample::Sample,
     selector::Selector,
@@ -130,6 +132,8 @@ pub struct Reply {
     pub(crate) result: Result<Sample, ReplyError>,
     #[cfg(feature = "unstable")]
     pub(crate) replier_id: Option<ZenohIdProto>,
+    #[cfg(feature = "trace-context")]
+    pub(crate) trace_context: Option<TraceContext>,
 }
 
 impl Reply {
@@ -156,6 +160,13 @@ impl Reply {
         self.replier_id.map(Into::into)
     }
 
+    /// Gets the W3C trace context of the zenoh instance that answered this Reply.
+    #[zenoh_macros::unstable]
+    #[cfg(feature = "trace-context")]
+    pub fn trace_context(&self) -> Option<&TraceContext> {
+        self.trace_context.as_ref()
+    }
+
     /// Constructs an uninitialized empty Reply.
     #[zenoh_macros::internal]
     pub fn empty() -> Self {
@@ -163,6 +174,8 @@ impl Reply {
             result: Ok(Sample::empty()),
             #[cfg(feature = "unstable")]
             replier_id: None,
+            #[cfg(feature = "trace-context")]
+            trace_context: None,
         }
     }
 }
@@ -32,6 +32,8 @@ use {
     zenoh_protocol::core::EntityGlobalIdProto,
 };
 
+#[cfg(feature = "trace-context")]
+use crate::api::sample::TraceContext;
 #[zenoh_macros::unstable]
 use crate::api::selector::ZenohParameters;
 #[zenoh_macros::internal]
@@ -89,6 +91,8 @@ pub struct Query {
     pub(crate) eid: EntityId,
     pub(crate) value: Option<(ZBytes, Encoding)>,
     pub(crate) attachment: Option<ZBytes>,
+    #[cfg(feature = "trace-context")]
+    pub(crate) trace_context: Option<TraceContext>,
 }
 
 impl Query {
@@ -138,6 +142,13 @@ impl Query {
         self.attachment.as_mut()
     }
 
+    /// The W3C trace context of the `get` that issued this Query.
+    #[zenoh_macros::unstable]
+    #[cfg(feature = "trace-context")]
+    pub fn trace_context(&self) -> Option<&TraceContext> {
+        self.trace_context.as_ref()
+    }
+
     /// Sends a reply in the form of [`Sample`] to this Query.
     ///
     /// By default, queries only accept replies whose key expression intersects with the query's.
@@ -223,6 +234,8 @@ impl Query {
             eid: 0,
             value: None,
             attachment: None,
+            #[cfg(feature = "trace-context")]
+            trace_context: None,
         }
     }
 }
@@ -289,6 +302,10 @@ impl Query {
         let ext_sinfo = None;
         #[cfg(feature = "unstable")]
         let ext_sinfo = sample.source_info.into();
+        #[cfg(not(feature = "trace-context"))]
+        let ext_trace = None;
+        #[cfg(feature = "trace-context")]
+        let ext_trace = TraceContext::current().map(Into::into);
         self.inner.primitives.send_response(&mut Response {
             rid: self.inner.qid,
             wire_expr: WireExpr {
@@ -324,6 +341,7 @@ impl Query {
                 zid: self.inner.zid,
                 eid: self.eid,
             }),
+            ext_trace,
         });
         Ok(())
     }
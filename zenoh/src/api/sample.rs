@@ -78,6 +78,8 @@ pub(crate) struct DataInfo {
     pub source_id: Option<EntityGlobalId>,
     pub source_sn: Option<SourceSn>,
     pub qos: QoS,
+    #[cfg(feature = "trace-context")]
+    pub trace_context: Option<TraceContext>,
 }
 
 pub(crate) trait DataInfoIntoSample {
@@ -125,6 +127,8 @@ impl DataInfoIntoSample for DataInfo {
                 source_sn: self.source_sn,
             },
             attachment,
+            #[cfg(feature = "trace-context")]
+            trace_context: self.trace_context,
         }
     }
 }
@@ -163,6 +167,8 @@ impl DataInfoIntoSample for Option<DataInfo> {
                 #[cfg(feature = "unstable")]
                 source_info: SourceInfo::empty(),
                 attachment,
+                #[cfg(feature = "trace-context")]
+                trace_context: None,
             }
         }
     }
@@ -268,6 +274,179 @@ impl Default for SourceInfo {
     }
 }
 
+/// The W3C trace context of a [`Sample`], a [`Query`](crate::query::Query) or a [`Reply`](crate::query::Reply).
+///
+/// When the `trace-context` feature is enabled, the OpenTelemetry context of the current
+/// [`tracing::Span`] (as provided by `tracing-opentelemetry`) is automatically attached to
+/// publications, queries and replies. The receiving side can use [`TraceContext::context`]
+/// to set the parent of its own spans, correlating both ends in the tracing backend.
+///
+/// See <https://www.w3.org/TR/trace-context/>.
+#[zenoh_macros::unstable]
+#[cfg(feature = "trace-context")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+    trace_id: [u8; 16],
+    parent_id: [u8; 8],
+    trace_flags: u8,
+    tracestate: String,
+}
+
+#[cfg(feature = "trace-context")]
+impl TraceContext {
+    const VERSION: &'static str = "00";
+
+    /// Build a [`TraceContext`] from the W3C `traceparent` and `tracestate` headers.
+    ///
+    /// Returns `None` if `traceparent` is not valid.
+    pub fn new(traceparent: &str, tracestate: &str) -> Option<Self> {
+        fn parse_hex<const N: usize>(s: &str) -> Option<[u8; N]> {
+            if s.len() != 2 * N {
+                return None;
+            }
+            let mut bytes = [0u8; N];
+            for (i, b) in bytes.iter_mut().enumerate() {
+                *b = u8::from_str_radix(s.get(2 * i..2 * i + 2)?, 16).ok()?;
+            }
+            Some(bytes)
+        }
+        let mut parts = traceparent.split('-');
+        if parts.next()? != Self::VERSION {
+            return None;
+        }
+        let trace_id = parse_hex::<16>(parts.next()?)?;
+        let parent_id = parse_hex::<8>(parts.next()?)?;
+        let [trace_flags] = parse_hex::<1>(parts.next()?)?;
+        if parts.next().is_some() || trace_id == [0; 16] || parent_id == [0; 8] {
+            return None;
+        }
+        Some(Self {
+            trace_id,
+            parent_id,
+            trace_flags,
+            tracestate: tracestate.to_string(),
+        })
+    }
+
+    /// The trace context of the current [`tracing::Span`], if any.
+    pub(crate) fn current() -> Option<Self> {
+        use opentelemetry::trace::TraceContextExt;
+        use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+        let context = tracing::Span::current().context();
+        let span = context.span();
+        let span_context = span.span_context();
+        span_context.is_valid().then(|| Self {
+            trace_id: span_context.trace_id().to_bytes(),
+            parent_id: span_context.span_id().to_bytes(),
+            trace_flags: span_context.trace_flags().to_u8(),
+            tracestate: span_context.trace_state().header(),
+        })
+    }
+
+    /// The W3C `traceparent` header.
+    pub fn traceparent(&self) -> String {
+        use std::fmt::Write;
+
+        let mut traceparent = String::with_capacity(55);
+        traceparent.push_str(Self::VERSION);
+        traceparent.push('-');
+        for b in self.trace_id {
+            write!(traceparent, "{b:02x}").unwrap();
+        }
+        traceparent.push('-');
+        for b in self.parent_id {
+            write!(traceparent, "{b:02x}").unwrap();
+        }
+        write!(traceparent, "-{:02x}", self.trace_flags).unwrap();
+        traceparent
+    }
+
+    /// The W3C `tracestate` header.
+    pub fn tracestate(&self) -> &str {
+        &self.tracestate
+    }
+
+    /// The OpenTelemetry context having this trace context as remote parent.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use tracing_opentelemetry::OpenTelemetrySpanExt;
+    ///
+    /// let session = zenoh::open(zenoh::Config::default()).await.unwrap();
+    /// let subscriber = session.declare_subscriber("key/expression").await.unwrap();
+    /// while let Ok(sample) = subscriber.recv_async().await {
+    ///     let span = tracing::info_span!("on_sample");
+    ///     if let Some(trace_context) = sample.trace_context() {
+    ///         span.set_parent(trace_context.context());
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn context(&self) -> opentelemetry::Context {
+        use opentelemetry::trace::{
+            SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState,
+        };
+
+        let span_context = SpanContext::new(
+            TraceId::from_bytes(self.trace_id),
+            SpanId::from_bytes(self.parent_id),
+            TraceFlags::new(self.trace_flags),
+            true,
+            self.tracestate.parse().unwrap_or(TraceState::NONE),
+        );
+        opentelemetry::Context::new().with_remote_span_context(span_context)
+    }
+}
+
+#[cfg(feature = "trace-context")]
+impl<const ID: u8> From<zenoh_protocol::network::ext::TraceContextType<ID>> for TraceContext {
+    fn from(ext: zenoh_protocol::network::ext::TraceContextType<ID>) -> Self {
+        Self {
+            trace_id: ext.trace_id,
+            parent_id: ext.parent_id,
+            trace_flags: ext.trace_flags,
+            tracestate: ext.tracestate,
+        }
+    }
+}
+
+#[cfg(feature = "trace-context")]
+impl<const ID: u8> From<TraceContext> for zenoh_protocol::network::ext::TraceContextType<ID> {
+    fn from(trace_context: TraceContext) -> Self {
+        Self {
+            trace_id: trace_context.trace_id,
+            parent_id: trace_context.parent_id,
+            trace_flags: trace_context.trace_flags,
+            tracestate: trace_context.tracestate,
+        }
+    }
+}
+
+#[cfg(feature = "trace-context")]
+#[test]
+fn trace_context_traceparent() {
+    let traceparent = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+    let trace_context = TraceContext::new(traceparent, "congo=t61rcWkgMzE").unwrap();
+    assert_eq!(trace_context.traceparent(), traceparent);
+    assert_eq!(trace_context.tracestate(), "congo=t61rcWkgMzE");
+    assert!(TraceContext::new(
+        "01-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+        ""
+    )
+    .is_none());
+    assert!(TraceContext::new(
+        "00-00000000000000000000000000000000-b7ad6b7169203331-01",
+        ""
+    )
+    .is_none());
+    assert!(
+        TraceContext::new("00-0af7651916cd43dd8448eb211c80319c-b7ad6b71692033-01", "").is_none()
+    );
+}
+
 /// The kind of a `Sample`.
 #[repr(u8)]
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -315,6 +494,8 @@ pub struct SampleFields {
     #[cfg(feature = "unstable")]
     pub source_info: SourceInfo,
     pub attachment: Option<ZBytes>,
+    #[cfg(feature = "trace-context")]
+    pub trace_context: Option<TraceContext>,
 }
 
 impl From<Sample> for SampleFields {
@@ -333,6 +514,8 @@ impl From<Sample> for SampleFields {
             #[cfg(feature = "unstable")]
             source_info: sample.source_info,
             attachment: sample.attachment,
+            #[cfg(feature = "trace-context")]
+            trace_context: sample.trace_context,
         }
     }
 }
@@ -352,6 +535,8 @@ pub struct Sample {
     #[cfg(feature = "unstable")]
     pub(crate) source_info: SourceInfo,
     pub(crate) attachment: Option<ZBytes>,
+    #[cfg(feature = "trace-context")]
+    pub(crate) trace_context: Option<TraceContext>,
 }
 
 impl Sample {
@@ -431,6 +616,14 @@ impl Sample {
         self.attachment.as_mut()
     }
 
+    /// Gets the W3C trace context of the publication of this Sample.
+    #[zenoh_macros::unstable]
+    #[cfg(feature = "trace-context")]
+    #[inline]
+    pub fn trace_context(&self) -> Option<&TraceContext> {
+        self.trace_context.as_ref()
+    }
+
     /// Constructs an uninitialized empty Sample.
     #[zenoh_macros::internal]
     pub fn empty() -> Self {
@@ -446,6 +639,8 @@ impl Sample {
             #[cfg(feature = "unstable")]
             source_info: SourceInfo::empty(),
             attachment: None,
+            #[cfg(feature = "trace-context")]
+            trace_context: None,
         }
     }
 }
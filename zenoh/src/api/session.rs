@@ -71,6 +71,8 @@ use zenoh_shm::api::client_storage::ShmClientStorage;
 use zenoh_task::TaskController;
 
 use super::builders::close::{CloseBuilder, Closeable, Closee};
+#[cfg(feature = "trace-context")]
+use crate::api::sample::TraceContext;
 #[cfg(feature = "unstable")]
 use crate::api::selector::ZenohParameters;
 #[cfg(feature = "unstable")]
@@ -1842,6 +1844,8 @@ impl SessionInner {
                             #[cfg(feature = "unstable")]
                             source_info: SourceInfo::empty(),
                             attachment: None,
+                            #[cfg(feature = "trace-context")]
+                            trace_context: None,
                         });
                     }
                 });
@@ -2185,6 +2189,8 @@ impl SessionInner {
         let primitives = zread!(self.state).primitives()?;
         let timestamp = timestamp.or_else(|| self.runtime.new_timestamp());
         let wire_expr = key_expr.to_wire(self);
+        #[cfg(feature = "trace-context")]
+        let trace_context = TraceContext::current();
         if destination != Locality::SessionLocal {
             let body = match kind {
                 SampleKind::Put => PushBody::Put(Put {
@@ -2215,6 +2221,10 @@ impl SessionInner {
                 ext_qos: push::ext::QoSType::new(priority.into(), congestion_control, is_express),
                 ext_tstamp: None,
                 ext_nodeid: push::ext::NodeIdType::DEFAULT,
+                #[cfg(feature = "trace-context")]
+                ext_trace: trace_context.clone().map(Into::into),
+                #[cfg(not(feature = "trace-context"))]
+                ext_trace: None,
                 payload: body,
             };
             match &self.namespace {
@@ -2258,6 +2268,8 @@ impl SessionInner {
                     congestion_control,
                     is_express,
                 )),
+                #[cfg(feature = "trace-context")]
+                trace_context,
             };
 
             self.execute_subscriber_callbacks(
@@ -2328,6 +2340,8 @@ impl SessionInner {
                                     result: Err(ReplyError::new("Timeout", Encoding::ZENOH_STRING)),
                                     #[cfg(feature = "unstable")]
                                     replier_id: Some(session.zid().into()),
+                                    #[cfg(feature = "trace-context")]
+                                    trace_context: None,
                                 });
                             }
                         }
@@ -2353,6 +2367,8 @@ impl SessionInner {
         let primitives = state.primitives()?;
         drop(state);
 
+        #[cfg(feature = "trace-context")]
+        let trace_context = TraceContext::current();
         if destination != Locality::SessionLocal {
             let ext_attachment = attachment.clone().map(Into::into);
             primitives.send_request(&mut Request {
@@ -2364,6 +2380,10 @@ impl SessionInner {
                 ext_target: target,
                 ext_budget: None,
                 ext_timeout: Some(timeout),
+                #[cfg(feature = "trace-context")]
+                ext_trace: trace_context.clone().map(Into::into),
+                #[cfg(not(feature = "trace-context"))]
+                ext_trace: None,
                 payload: RequestBody::Query(zenoh_protocol::zenoh::Query {
                     consolidation,
                     parameters: parameters.to_string(),
@@ -2397,6 +2417,8 @@ impl SessionInner {
                     payload: v.0.clone().into(),
                 }),
                 attachment,
+                #[cfg(feature = "trace-context")]
+                trace_context,
             );
         }
         Ok(())
@@ -2426,6 +2448,8 @@ impl SessionInner {
                                     result: Err(ReplyError::new("Timeout", Encoding::ZENOH_STRING)),
                                     #[cfg(feature = "unstable")]
                                     replier_id: Some(session.zid().into()),
+                                    #[cfg(feature = "trace-context")]
+                                    trace_context: None,
                                 });
                             }
                         }
@@ -2467,6 +2491,7 @@ impl SessionInner {
         _consolidation: ConsolidationMode,
         body: Option<QueryBodyType>,
         attachment: Option<ZBytes>,
+        #[cfg(feature = "trace-context")] trace_context: Option<TraceContext>,
     ) {
         let (primitives, key_expr, queryables) = {
             let state = zread!(self.state);
@@ -2528,6 +2553,8 @@ impl SessionInner {
             eid: 0,
             value: body.map(|b| (b.payload.into(), b.encoding.into())),
             attachment,
+            #[cfg(feature = "trace-context")]
+            trace_context,
         };
         for (eid, cb) in queryables {
             query.eid = eid;
@@ -2702,9 +2729,13 @@ impl Primitives for WeakSession {
                                         #[cfg(feature = "unstable")]
                                         source_info: SourceInfo::empty(),
                                         attachment: None,
+                                        #[cfg(feature = "trace-context")]
+                                        trace_context: None,
                                     }),
                                     #[cfg(feature = "unstable")]
                                     replier_id: None,
+                                    #[cfg(feature = "trace-context")]
+                                    trace_context: None,
                                 };
 
                                 query.callback.call(reply);
@@ -2812,6 +2843,8 @@ impl Primitives for WeakSession {
                     qos: QoS::from(msg.ext_qos),
                     source_id: m.ext_sinfo.as_ref().map(|i| i.id.into()),
                     source_sn: m.ext_sinfo.as_ref().map(|i| i.sn),
+                    #[cfg(feature = "trace-context")]
+                    trace_context: msg.ext_trace.take().map(Into::into),
                 };
                 self.execute_subscriber_callbacks(
                     false,
@@ -2832,6 +2865,8 @@ impl Primitives for WeakSession {
                     qos: QoS::from(msg.ext_qos),
                     source_id: m.ext_sinfo.as_ref().map(|i| i.id.into()),
                     source_sn: m.ext_sinfo.as_ref().map(|i| i.sn),
+                    #[cfg(feature = "trace-context")]
+                    trace_context: msg.ext_trace.take().map(Into::into),
                 };
                 self.execute_subscriber_callbacks(
                     false,
@@ -2859,12 +2894,16 @@ impl Primitives for WeakSession {
                 m.consolidation,
                 mem::take(&mut m.ext_body),
                 mem::take(&mut m.ext_attachment).map(Into::into),
+                #[cfg(feature = "trace-context")]
+                msg.ext_trace.take().map(Into::into),
             ),
         }
     }
 
     fn send_response(&self, msg: &mut Response) {
         trace!("recv Response {:?}", msg);
+        #[cfg(feature = "trace-context")]
+        let trace_context: Option<TraceContext> = msg.ext_trace.take().map(Into::into);
         match &mut msg.payload {
             ResponseBody::Err(e) => {
                 let mut state = zwrite!(self.state);
@@ -2882,6 +2921,8 @@ impl Primitives for WeakSession {
                             }),
                             #[cfg(feature = "unstable")]
                             replier_id: mem::take(&mut msg.ext_respid).map(|rid| rid.zid),
+                            #[cfg(feature = "trace-context")]
+                            trace_context,
                         };
                         callback.call(new_reply);
                     }
@@ -2942,6 +2983,8 @@ impl Primitives for WeakSession {
                                     qos: QoS::from(msg.ext_qos),
                                     source_id: ext_sinfo.as_ref().map(|i| i.id.into()),
                                     source_sn: ext_sinfo.as_ref().map(|i| i.sn),
+                                    #[cfg(feature = "trace-context")]
+                                    trace_context: trace_context.clone(),
                                 },
                                 attachment: mem::take(_attachment).map(Into::into),
                             },
@@ -2959,6 +3002,8 @@ impl Primitives for WeakSession {
                                     qos: QoS::from(msg.ext_qos),
                                     source_id: ext_sinfo.as_ref().map(|i| i.id.into()),
                                     source_sn: ext_sinfo.as_ref().map(|i| i.sn),
+                                    #[cfg(feature = "trace-context")]
+                                    trace_context: trace_context.clone(),
                                 },
                                 attachment: mem::take(_attachment).map(Into::into),
                             },
@@ -2974,6 +3019,8 @@ impl Primitives for WeakSession {
                             result: Ok(sample),
                             #[cfg(feature = "unstable")]
                             replier_id: mem::take(&mut msg.ext_respid).map(|rid| rid.zid),
+                            #[cfg(feature = "trace-context")]
+                            trace_context,
                         };
                         let callback =
                             match query.reception_mode {
@@ -229,6 +229,9 @@ pub mod sample {
     #[zenoh_macros::unstable]
     pub use crate::api::sample::Locality;
     #[zenoh_macros::unstable]
+    #[cfg(feature = "trace-context")]
+    pub use crate::api::sample::TraceContext;
+    #[zenoh_macros::unstable]
     pub use crate::api::sample::{SourceInfo, SourceSn};
     pub use crate::api::{
         builders::sample::{
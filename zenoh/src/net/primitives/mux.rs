@@ -11,6 +11,8 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+#[cfg(feature = "stats")]
+use std::sync::Arc;
 use std::sync::OnceLock;
 
 use arc_swap::ArcSwap;
@@ -24,6 +26,8 @@ use zenoh_protocol::{
 use zenoh_transport::{multicast::TransportMulticast, unicast::TransportUnicast};
 
 use super::{EPrimitives, Primitives};
+#[cfg(feature = "stats")]
+use crate::net::routing::interceptor::KeyExprStats;
 use crate::net::routing::{
     dispatcher::face::{Face, WeakFace},
     interceptor::{InterceptorTrait, InterceptorsChain},
@@ -34,14 +38,33 @@ pub struct Mux {
     pub handler: TransportUnicast,
     pub(crate) face: OnceLock<WeakFace>,
     pub(crate) interceptor: ArcSwap<InterceptorsChain>,
+    #[cfg(feature = "stats")]
+    pub(crate) keyexpr_stats: Arc<KeyExprStats>,
 }
 
 impl Mux {
-    pub(crate) fn new(handler: TransportUnicast, interceptor: InterceptorsChain) -> Mux {
+    pub(crate) fn new(
+        handler: TransportUnicast,
+        interceptor: InterceptorsChain,
+        #[cfg(feature = "stats")] keyexpr_stats: Arc<KeyExprStats>,
+    ) -> Mux {
         Mux {
             handler,
             face: OnceLock::new(),
             interceptor: ArcSwap::new(interceptor.into()),
+            #[cfg(feature = "stats")]
+            keyexpr_stats,
+        }
+    }
+
+    /// Schedules an intercepted message, accounting for congestion drops in the key expression stats.
+    fn schedule(&self, ctx: &mut RoutingContext<NetworkMessageMut>) {
+        #[allow(unused_variables)]
+        let res = self.handler.schedule(ctx.msg.as_mut());
+        #[cfg(feature = "stats")]
+        if matches!(res, Ok(false)) && !self.keyexpr_stats.is_empty() {
+            self.keyexpr_stats
+                .inc_congestion_dropped_msgs(ctx.full_keyexpr());
         }
     }
 }
@@ -129,7 +152,7 @@ impl EPrimitives for Mux {
             let cache = cache_guard.as_ref().and_then(|c| c.get_ref().as_ref());
 
             if interceptor.intercept(&mut ctx, cache) {
-                let _ = self.handler.schedule(ctx.msg);
+                self.schedule(&mut ctx);
             }
         } else {
             tracing::error!("Uninitialized multiplexer!");
@@ -158,7 +181,7 @@ impl EPrimitives for Mux {
             let cache = cache_guard.as_ref().and_then(|c| c.get_ref().as_ref());
 
             if interceptor.intercept(&mut ctx, cache) {
-                let _ = self.handler.schedule(ctx.msg);
+                self.schedule(&mut ctx);
             } else {
                 // request was blocked by an interceptor, we need to send response final to avoid timeout error
                 face.send_response_final(&mut ResponseFinal {
@@ -193,7 +216,7 @@ impl EPrimitives for Mux {
             let cache = cache_guard.as_ref().and_then(|c| c.get_ref().as_ref());
 
             if interceptor.intercept(&mut ctx, cache) {
-                let _ = self.handler.schedule(ctx.msg);
+                self.schedule(&mut ctx);
             }
         } else {
             tracing::error!("Uninitialized multiplexer!");
@@ -372,6 +372,7 @@ pub fn route_data(
                                     ext_qos: msg.ext_qos,
                                     ext_tstamp: None,
                                     ext_nodeid: ext::NodeIdType { node_id: context },
+                                    ext_trace: msg.ext_trace.clone(),
                                     payload: msg.payload.clone(),
                                 },
                                 reliability,
@@ -325,6 +325,7 @@ impl Timed for QueryCleanup {
                     ext_qos: response::ext::QoSType::RESPONSE,
                     ext_tstamp: None,
                     ext_respid,
+                    ext_trace: None,
                 },
             );
             let queries_lock = zwrite!(self.tables.queries_lock);
@@ -554,6 +555,7 @@ pub fn route_query(tables_ref: &Arc<TablesLock>, face: &Arc<FaceState>, msg: &mu
                             ext_target: msg.ext_target,
                             ext_budget: msg.ext_budget,
                             ext_timeout: msg.ext_timeout,
+                            ext_trace: msg.ext_trace.clone(),
                             payload: msg.payload.clone(),
                         });
                     }
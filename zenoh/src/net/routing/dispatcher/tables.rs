@@ -31,6 +31,8 @@ use zenoh_result::ZResult;
 
 use super::face::FaceState;
 pub use super::resource::*;
+#[cfg(feature = "stats")]
+use crate::net::routing::interceptor::KeyExprStats;
 use crate::net::{
     routing::{
         hat::HatTrait,
@@ -79,6 +81,8 @@ pub struct Tables {
     pub(crate) mcast_groups: Vec<Arc<FaceState>>,
     pub(crate) mcast_faces: Vec<Arc<FaceState>>,
     pub(crate) interceptors: Vec<InterceptorFactory>,
+    #[cfg(feature = "stats")]
+    pub(crate) keyexpr_stats: Arc<KeyExprStats>,
     pub(crate) hat: Box<dyn Any + Send + Sync>,
     pub(crate) routes_version: RoutesVersion,
     pub(crate) next_interceptor_version: AtomicUsize,
@@ -100,6 +104,8 @@ impl Tables {
             Duration::from_millis(unwrap_or_default!(config.queries_default_timeout()));
         let interests_timeout =
             Duration::from_millis(unwrap_or_default!(config.routing().interests().timeout()));
+        #[cfg(feature = "stats")]
+        let keyexpr_stats = Arc::new(KeyExprStats::new(config.stats().key_exprs()));
         Ok(Tables {
             zid,
             whatami,
@@ -113,7 +119,13 @@ impl Tables {
             faces: HashMap::new(),
             mcast_groups: vec![],
             mcast_faces: vec![],
-            interceptors: interceptor_factories(config)?,
+            interceptors: interceptor_factories(
+                config,
+                #[cfg(feature = "stats")]
+                &keyexpr_stats,
+            )?,
+            #[cfg(feature = "stats")]
+            keyexpr_stats,
             hat: hat_code.new_tables(router_peers_failover_brokering),
             routes_version: 0,
             next_interceptor_version: AtomicUsize::new(0),
@@ -177,7 +189,13 @@ impl TablesLock {
     #[allow(dead_code)]
     pub(crate) fn regen_interceptors(&self, config: &Config) -> ZResult<()> {
         let mut tables = zwrite!(self.tables);
-        tables.interceptors = interceptor_factories(config)?;
+        #[cfg(feature = "stats")]
+        let keyexpr_stats = tables.keyexpr_stats.clone();
+        tables.interceptors = interceptor_factories(
+            config,
+            #[cfg(feature = "stats")]
+            &keyexpr_stats,
+        )?;
         drop(tables);
         let tables = zread!(self.tables);
         let version = tables
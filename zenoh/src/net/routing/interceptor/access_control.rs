@@ -41,9 +41,13 @@ use super::{
     authorization::PolicyEnforcer, EgressInterceptor, IngressInterceptor, InterceptorFactory,
     InterceptorFactoryTrait, InterceptorLinkWrapper, InterceptorTrait,
 };
+#[cfg(feature = "stats")]
+use super::{count_dropped_msgs, DropCause, KeyExprStats};
 use crate::net::routing::{interceptor::authorization::SubjectQuery, RoutingContext};
 pub struct AclEnforcer {
     enforcer: Arc<PolicyEnforcer>,
+    #[cfg(feature = "stats")]
+    keyexpr_stats: Arc<KeyExprStats>,
 }
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AuthSubject {
@@ -167,6 +171,7 @@ impl IngressAclEnforcer {
 
 pub(crate) fn acl_interceptor_factories(
     acl_config: &AclConfig,
+    #[cfg(feature = "stats")] keyexpr_stats: &Arc<KeyExprStats>,
 ) -> ZResult<Vec<InterceptorFactory>> {
     let mut res: Vec<InterceptorFactory> = vec![];
 
@@ -177,6 +182,8 @@ pub(crate) fn acl_interceptor_factories(
                 tracing::debug!("Access control is enabled");
                 res.push(Box::new(AclEnforcer {
                     enforcer: Arc::new(policy_enforcer),
+                    #[cfg(feature = "stats")]
+                    keyexpr_stats: keyexpr_stats.clone(),
                 }))
             }
             Err(e) => bail!("Access control not enabled due to: {}", e),
@@ -279,16 +286,30 @@ impl InterceptorFactoryTrait for AclEnforcer {
                 self.enforcer.default_permission
             );
         }
-        let ingress_interceptor = Box::new(IngressAclEnforcer {
+        let ingress_interceptor: IngressInterceptor = Box::new(IngressAclEnforcer {
             policy_enforcer: self.enforcer.clone(),
             zid,
             subject: auth_subjects.clone(),
         });
-        let egress_interceptor = Box::new(EgressAclEnforcer {
+        let egress_interceptor: EgressInterceptor = Box::new(EgressAclEnforcer {
             policy_enforcer: self.enforcer.clone(),
             zid,
             subject: auth_subjects,
         });
+        #[cfg(feature = "stats")]
+        let ingress_interceptor = count_dropped_msgs(
+            ingress_interceptor,
+            &self.keyexpr_stats,
+            DropCause::AccessControl,
+            InterceptorFlow::Ingress,
+        );
+        #[cfg(feature = "stats")]
+        let egress_interceptor = count_dropped_msgs(
+            egress_interceptor,
+            &self.keyexpr_stats,
+            DropCause::AccessControl,
+            InterceptorFlow::Egress,
+        );
         (
             self.enforcer
                 .interface_enabled
@@ -41,6 +41,7 @@ use crate::net::routing::interceptor::*;
 
 pub(crate) fn downsampling_interceptor_factories(
     config: &Vec<DownsamplingItemConf>,
+    #[cfg(feature = "stats")] keyexpr_stats: &Arc<KeyExprStats>,
 ) -> ZResult<Vec<InterceptorFactory>> {
     let mut res: Vec<InterceptorFactory> = vec![];
 
@@ -53,7 +54,11 @@ pub(crate) fn downsampling_interceptor_factories(
             }
         }
 
-        res.push(Box::new(DownsamplingInterceptorFactory::new(ds.clone())));
+        res.push(Box::new(DownsamplingInterceptorFactory::new(
+            ds.clone(),
+            #[cfg(feature = "stats")]
+            keyexpr_stats.clone(),
+        )));
     }
 
     Ok(res)
@@ -65,10 +70,15 @@ pub struct DownsamplingInterceptorFactory {
     rules: NEVec<DownsamplingRuleConf>,
     flows: InterfaceEnabled,
     messages: Arc<DownsamplingFilters>,
+    #[cfg(feature = "stats")]
+    keyexpr_stats: Arc<KeyExprStats>,
 }
 
 impl DownsamplingInterceptorFactory {
-    pub fn new(conf: DownsamplingItemConf) -> Self {
+    pub(crate) fn new(
+        conf: DownsamplingItemConf,
+        #[cfg(feature = "stats")] keyexpr_stats: Arc<KeyExprStats>,
+    ) -> Self {
         Self {
             interfaces: conf.interfaces,
             rules: conf.rules,
@@ -78,6 +88,8 @@ impl DownsamplingInterceptorFactory {
                 egress: true,
             }),
             messages: Arc::new((&conf.messages).into()),
+            #[cfg(feature = "stats")]
+            keyexpr_stats,
         }
     }
 }
@@ -123,24 +135,40 @@ impl InterceptorFactoryTrait for DownsamplingInterceptorFactory {
         );
         (
             self.flows.ingress.then(|| {
-                Box::new(ComputeOnMiss::new(DownsamplingInterceptor::new(
+                let interceptor = Box::new(ComputeOnMiss::new(DownsamplingInterceptor::new(
                     self.messages.clone(),
                     &self.rules,
                     #[cfg(feature = "stats")]
                     InterceptorFlow::Ingress,
                     #[cfg(feature = "stats")]
                     transport.get_stats().unwrap_or_default(),
-                ))) as IngressInterceptor
+                ))) as IngressInterceptor;
+                #[cfg(feature = "stats")]
+                let interceptor = count_dropped_msgs(
+                    interceptor,
+                    &self.keyexpr_stats,
+                    DropCause::Downsampling,
+                    InterceptorFlow::Ingress,
+                );
+                interceptor
             }),
             self.flows.egress.then(|| {
-                Box::new(ComputeOnMiss::new(DownsamplingInterceptor::new(
+                let interceptor = Box::new(ComputeOnMiss::new(DownsamplingInterceptor::new(
                     self.messages.clone(),
                     &self.rules,
                     #[cfg(feature = "stats")]
                     InterceptorFlow::Egress,
                     #[cfg(feature = "stats")]
                     transport.get_stats().unwrap_or_default(),
-                ))) as EgressInterceptor
+                ))) as EgressInterceptor;
+                #[cfg(feature = "stats")]
+                let interceptor = count_dropped_msgs(
+                    interceptor,
+                    &self.keyexpr_stats,
+                    DropCause::Downsampling,
+                    InterceptorFlow::Egress,
+                );
+                interceptor
             }),
         )
     }
@@ -0,0 +1,382 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//! ⚠️ WARNING ⚠️
+//!
+//! This module is intended for Zenoh's internal use.
+//!
+//! [Click here for Zenoh's documentation](https://docs.rs/zenoh/latest/zenoh)
+
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use zenoh_buffers::buffer::Buffer;
+use zenoh_config::InterceptorFlow;
+use zenoh_keyexpr::{keyexpr, OwnedKeyExpr};
+use zenoh_protocol::{
+    network::{NetworkBodyMut, NetworkMessageMut, Push, Request, Response},
+    zenoh::{PushBody, Reply, RequestBody, ResponseBody},
+};
+use zenoh_transport::{multicast::TransportMulticast, unicast::TransportUnicast};
+
+use super::{
+    ComputeOnMiss, EgressInterceptor, IngressInterceptor, Interceptor, InterceptorFactory,
+    InterceptorFactoryTrait, InterceptorTrait, RoutingContext,
+};
+
+macro_rules! keyexpr_counters {
+    ($($(#[doc = $help:literal])* $name:ident,)*) => {
+        #[derive(Default)]
+        struct KeyExprCounters {
+            $($name: AtomicUsize,)*
+        }
+
+        impl KeyExprCounters {
+            fn report(&self) -> serde_json::Value {
+                serde_json::json!({
+                    $(stringify!($name): self.$name.load(Ordering::Relaxed),)*
+                })
+            }
+        }
+
+        impl KeyExprStats {
+            /// Returns the per key expression counters in the OpenMetrics text format.
+            pub(crate) fn openmetrics_text(&self) -> String {
+                let mut s = String::new();
+                if self.entries.is_empty() {
+                    return s;
+                }
+                $(
+                    s.push_str(concat!(
+                        "# HELP zenoh_keyexpr_", stringify!($name), $($help,)* "\n",
+                        "# TYPE zenoh_keyexpr_", stringify!($name), " counter\n",
+                    ));
+                    for (key_expr, counters) in &self.entries {
+                        s.push_str(&format!(
+                            concat!("zenoh_keyexpr_", stringify!($name), "{{key_expr=\"{}\"}} {}\n"),
+                            key_expr,
+                            counters.$name.load(Ordering::Relaxed),
+                        ));
+                    }
+                )*
+                s
+            }
+        }
+    };
+}
+
+keyexpr_counters! {
+    /// Counter of received network messages.
+    rx_msgs,
+    /// Counter of received bytes in network message payloads.
+    rx_bytes,
+    /// Counter of sent network messages.
+    tx_msgs,
+    /// Counter of sent bytes in network message payloads.
+    tx_bytes,
+    /// Counter of network messages dropped due to congestion.
+    tx_congestion_dropped_msgs,
+    /// Counter of received network messages dropped by downsampling.
+    rx_downsampler_dropped_msgs,
+    /// Counter of sent network messages dropped by downsampling.
+    tx_downsampler_dropped_msgs,
+    /// Counter of received network messages denied by access control.
+    rx_acl_denied_msgs,
+    /// Counter of sent network messages denied by access control.
+    tx_acl_denied_msgs,
+}
+
+/// Message counters maintained for each of the key expressions configured in `stats/key_exprs`.
+///
+/// A message is accounted for every configured key expression its key expression intersects.
+#[derive(Default)]
+pub(crate) struct KeyExprStats {
+    entries: Vec<(OwnedKeyExpr, KeyExprCounters)>,
+}
+
+impl KeyExprStats {
+    pub(crate) fn new(key_exprs: &[OwnedKeyExpr]) -> Self {
+        let mut entries: Vec<(OwnedKeyExpr, KeyExprCounters)> = vec![];
+        for key_expr in key_exprs {
+            if entries.iter().any(|(ke, _)| ke == key_expr) {
+                tracing::warn!("Key expression '{key_expr}' is repeated in stats configuration");
+                continue;
+            }
+            entries.push((key_expr.clone(), KeyExprCounters::default()));
+        }
+        Self { entries }
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the configured key expressions along with a JSON report of their counters.
+    pub(crate) fn report(&self) -> impl Iterator<Item = (&OwnedKeyExpr, serde_json::Value)> {
+        self.entries
+            .iter()
+            .map(|(key_expr, counters)| (key_expr, counters.report()))
+    }
+
+    fn matching(&self, key_expr: &keyexpr) -> Vec<usize> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(id, (ke, _))| ke.intersects(key_expr).then_some(id))
+            .collect()
+    }
+
+    fn inc_matching(
+        &self,
+        key_expr: Option<&keyexpr>,
+        counter: impl Fn(&KeyExprCounters) -> &AtomicUsize,
+    ) {
+        if let Some(key_expr) = key_expr {
+            for (ke, counters) in &self.entries {
+                if ke.intersects(key_expr) {
+                    counter(counters).fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn inc_congestion_dropped_msgs(&self, key_expr: Option<&keyexpr>) {
+        self.inc_matching(key_expr, |c| &c.tx_congestion_dropped_msgs);
+    }
+
+    fn inc_dropped_msgs(
+        &self,
+        cause: DropCause,
+        flow: InterceptorFlow,
+        key_expr: Option<&keyexpr>,
+    ) {
+        match (cause, flow) {
+            (DropCause::Downsampling, InterceptorFlow::Ingress) => {
+                self.inc_matching(key_expr, |c| &c.rx_downsampler_dropped_msgs)
+            }
+            (DropCause::Downsampling, InterceptorFlow::Egress) => {
+                self.inc_matching(key_expr, |c| &c.tx_downsampler_dropped_msgs)
+            }
+            (DropCause::AccessControl, InterceptorFlow::Ingress) => {
+                self.inc_matching(key_expr, |c| &c.rx_acl_denied_msgs)
+            }
+            (DropCause::AccessControl, InterceptorFlow::Egress) => {
+                self.inc_matching(key_expr, |c| &c.tx_acl_denied_msgs)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum DropCause {
+    Downsampling,
+    AccessControl,
+}
+
+/// Wraps `interceptor` so that the messages it rejects are accounted for in `stats`.
+pub(crate) fn count_dropped_msgs(
+    interceptor: Interceptor,
+    stats: &Arc<KeyExprStats>,
+    cause: DropCause,
+    flow: InterceptorFlow,
+) -> Interceptor {
+    if stats.is_empty() {
+        return interceptor;
+    }
+    Box::new(DropCounter {
+        interceptor,
+        stats: stats.clone(),
+        cause,
+        flow,
+    })
+}
+
+struct DropCounter {
+    interceptor: Interceptor,
+    stats: Arc<KeyExprStats>,
+    cause: DropCause,
+    flow: InterceptorFlow,
+}
+
+impl InterceptorTrait for DropCounter {
+    #[inline]
+    fn compute_keyexpr_cache(&self, key_expr: &keyexpr) -> Option<Box<dyn Any + Send + Sync>> {
+        self.interceptor.compute_keyexpr_cache(key_expr)
+    }
+
+    fn intercept(
+        &self,
+        ctx: &mut RoutingContext<NetworkMessageMut>,
+        cache: Option<&Box<dyn Any + Send + Sync>>,
+    ) -> bool {
+        let res = self.interceptor.intercept(ctx, cache);
+        if !res {
+            self.stats
+                .inc_dropped_msgs(self.cause, self.flow, ctx.full_keyexpr());
+        }
+        res
+    }
+}
+
+pub(crate) fn keyexpr_stats_interceptor_factories(
+    stats: &Arc<KeyExprStats>,
+) -> Vec<InterceptorFactory> {
+    if stats.is_empty() {
+        return vec![];
+    }
+    vec![Box::new(KeyExprStatsInterceptorFactory {
+        stats: stats.clone(),
+    })]
+}
+
+pub(crate) struct KeyExprStatsInterceptorFactory {
+    stats: Arc<KeyExprStats>,
+}
+
+impl KeyExprStatsInterceptorFactory {
+    fn interceptor(&self, flow: InterceptorFlow) -> Box<ComputeOnMiss<KeyExprStatsInterceptor>> {
+        Box::new(ComputeOnMiss::new(KeyExprStatsInterceptor {
+            stats: self.stats.clone(),
+            flow,
+        }))
+    }
+}
+
+impl InterceptorFactoryTrait for KeyExprStatsInterceptorFactory {
+    fn new_transport_unicast(
+        &self,
+        _transport: &TransportUnicast,
+    ) -> (Option<IngressInterceptor>, Option<EgressInterceptor>) {
+        (
+            Some(self.interceptor(InterceptorFlow::Ingress)),
+            Some(self.interceptor(InterceptorFlow::Egress)),
+        )
+    }
+
+    fn new_transport_multicast(
+        &self,
+        _transport: &TransportMulticast,
+    ) -> Option<EgressInterceptor> {
+        Some(self.interceptor(InterceptorFlow::Egress))
+    }
+
+    fn new_peer_multicast(&self, _transport: &TransportMulticast) -> Option<IngressInterceptor> {
+        Some(self.interceptor(InterceptorFlow::Ingress))
+    }
+}
+
+pub(crate) struct KeyExprStatsInterceptor {
+    stats: Arc<KeyExprStats>,
+    flow: InterceptorFlow,
+}
+
+impl KeyExprStatsInterceptor {
+    /// Returns the payload size of data messages, `None` for other messages.
+    fn payload_size(msg: &NetworkMessageMut) -> Option<usize> {
+        match &msg.body {
+            NetworkBodyMut::Push(Push { payload, .. })
+            | NetworkBodyMut::Response(Response {
+                payload: ResponseBody::Reply(Reply { payload, .. }),
+                ..
+            }) => match payload {
+                PushBody::Put(put) => Some(put.payload.len()),
+                PushBody::Del(_) => Some(0),
+            },
+            NetworkBodyMut::Response(Response {
+                payload: ResponseBody::Err(err),
+                ..
+            }) => Some(err.payload.len()),
+            NetworkBodyMut::Request(Request {
+                payload: RequestBody::Query(query),
+                ..
+            }) => Some(
+                query
+                    .ext_body
+                    .as_ref()
+                    .map(|body| body.payload.len())
+                    .unwrap_or(0),
+            ),
+            NetworkBodyMut::ResponseFinal(_)
+            | NetworkBodyMut::Interest(_)
+            | NetworkBodyMut::Declare(_)
+            | NetworkBodyMut::OAM(_) => None,
+        }
+    }
+}
+
+impl InterceptorTrait for KeyExprStatsInterceptor {
+    fn compute_keyexpr_cache(&self, key_expr: &keyexpr) -> Option<Box<dyn Any + Send + Sync>> {
+        Some(Box::new(self.stats.matching(key_expr)))
+    }
+
+    fn intercept(
+        &self,
+        ctx: &mut RoutingContext<NetworkMessageMut>,
+        cache: Option<&Box<dyn Any + Send + Sync>>,
+    ) -> bool {
+        let Some(size) = Self::payload_size(&ctx.msg) else {
+            return true;
+        };
+        if let Some(ids) = cache.and_then(|c| c.downcast_ref::<Vec<usize>>()) {
+            for id in ids {
+                let (_, counters) = &self.stats.entries[*id];
+                let (msgs, bytes) = match self.flow {
+                    InterceptorFlow::Ingress => (&counters.rx_msgs, &counters.rx_bytes),
+                    InterceptorFlow::Egress => (&counters.tx_msgs, &counters.tx_bytes),
+                };
+                msgs.fetch_add(1, Ordering::Relaxed);
+                bytes.fetch_add(size, Ordering::Relaxed);
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyexpr_stats_matching() {
+        let stats = KeyExprStats::new(&[
+            OwnedKeyExpr::new("demo/**").unwrap(),
+            OwnedKeyExpr::new("demo/a").unwrap(),
+            OwnedKeyExpr::new("demo/**").unwrap(),
+        ]);
+        assert_eq!(stats.entries.len(), 2);
+        assert_eq!(stats.matching(keyexpr::new("demo/a").unwrap()), vec![0, 1]);
+        assert_eq!(stats.matching(keyexpr::new("demo/*").unwrap()), vec![0, 1]);
+        assert_eq!(stats.matching(keyexpr::new("demo/b").unwrap()), vec![0]);
+        assert!(stats.matching(keyexpr::new("other").unwrap()).is_empty());
+
+        stats.inc_dropped_msgs(
+            DropCause::AccessControl,
+            InterceptorFlow::Ingress,
+            keyexpr::new("demo/b").ok(),
+        );
+        stats.inc_congestion_dropped_msgs(keyexpr::new("demo/a").ok());
+        let text = stats.openmetrics_text();
+        assert!(text.contains("# TYPE zenoh_keyexpr_rx_acl_denied_msgs counter\n"));
+        assert!(text.contains("zenoh_keyexpr_rx_acl_denied_msgs{key_expr=\"demo/**\"} 1\n"));
+        assert!(text.contains("zenoh_keyexpr_rx_acl_denied_msgs{key_expr=\"demo/a\"} 0\n"));
+        assert!(text.contains("zenoh_keyexpr_tx_congestion_dropped_msgs{key_expr=\"demo/a\"} 1\n"));
+        assert!(KeyExprStats::new(&[]).openmetrics_text().is_empty());
+    }
+}
@@ -28,6 +28,9 @@ use std::any::Any;
 
 mod low_pass;
 use low_pass::low_pass_interceptor_factories;
+
+mod rate_limit;
+use rate_limit::rate_limit_interceptor_factories;
 use zenoh_config::{Config, InterceptorFlow, InterceptorLink};
 use zenoh_keyexpr::{keyexpr, OwnedKeyExpr};
 use zenoh_protocol::network::NetworkMessageMut;
@@ -42,6 +45,16 @@ use crate::net::routing::interceptor::downsampling::downsampling_interceptor_fac
 pub mod qos_overwrite;
 use crate::net::routing::interceptor::qos_overwrite::qos_overwrite_interceptor_factories;
 
+#[cfg(feature = "stats")]
+mod keyexpr_stats;
+#[cfg(feature = "stats")]
+use std::sync::Arc;
+
+#[cfg(feature = "stats")]
+pub(crate) use keyexpr_stats::KeyExprStats;
+#[cfg(feature = "stats")]
+use keyexpr_stats::{count_dropped_msgs, keyexpr_stats_interceptor_factories, DropCause};
+
 #[derive(Default, Debug)]
 pub struct InterfaceEnabled {
     pub ingress: bool,
@@ -108,7 +121,10 @@ pub(crate) trait InterceptorFactoryTrait {
 
 pub(crate) type InterceptorFactory = Box<dyn InterceptorFactoryTrait + Send + Sync>;
 
-pub(crate) fn interceptor_factories(config: &Config) -> ZResult<Vec<InterceptorFactory>> {
+pub(crate) fn interceptor_factories(
+    config: &Config,
+    #[cfg(feature = "stats")] keyexpr_stats: &Arc<KeyExprStats>,
+) -> ZResult<Vec<InterceptorFactory>> {
     let mut res: Vec<InterceptorFactory> = vec![];
     // Uncomment to log the interceptors initialisation
     // res.push(Box::new(LoggerInterceptor {}));
@@ -119,10 +135,22 @@ pub(crate) fn interceptor_factories(config: &Config) -> ZResult<Vec<InterceptorF
             res.extend((test_interceptors.as_ref())());
         }
     }
-    res.extend(downsampling_interceptor_factories(config.downsampling())?);
-    res.extend(acl_interceptor_factories(config.access_control())?);
+    res.extend(downsampling_interceptor_factories(
+        config.downsampling(),
+        #[cfg(feature = "stats")]
+        keyexpr_stats,
+    )?);
+    res.extend(acl_interceptor_factories(
+        config.access_control(),
+        #[cfg(feature = "stats")]
+        keyexpr_stats,
+    )?);
     res.extend(qos_overwrite_interceptor_factories(config.qos().network())?);
     res.extend(low_pass_interceptor_factories(config.low_pass_filter())?);
+    res.extend(rate_limit_interceptor_factories(config.rate_limit())?);
+    // Must come last to only account for messages accepted by the other interceptors
+    #[cfg(feature = "stats")]
+    res.extend(keyexpr_stats_interceptor_factories(keyexpr_stats));
     Ok(res)
 }
 
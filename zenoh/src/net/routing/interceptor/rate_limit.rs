@@ -0,0 +1,522 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//! ⚠️ WARNING ⚠️
+//!
+//! This module is intended for Zenoh's internal use.
+//!
+//! [Click here for Zenoh's documentation](https://docs.rs/zenoh/latest/zenoh)
+
+use std::{
+    any::Any,
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use nonempty_collections::NEVec;
+use zenoh_buffers::buffer::Buffer;
+use zenoh_config::{
+    CertCommonName, InterceptorFlow, InterceptorLink, Interface, RateLimitConf, RateLimitMessage,
+    RateLimitPolicy, Username,
+};
+use zenoh_core::zlock;
+use zenoh_keyexpr::{keyexpr, OwnedKeyExpr};
+use zenoh_link::LinkAuthId;
+use zenoh_protocol::{
+    network::{NetworkBodyMut, NetworkMessageMut, Push, Request, Response},
+    zenoh::{PushBody, Reply, RequestBody, ResponseBody},
+};
+use zenoh_result::ZResult;
+use zenoh_transport::{multicast::TransportMulticast, unicast::TransportUnicast};
+
+use super::{
+    ComputeOnMiss, EgressInterceptor, IngressInterceptor, InterceptorFactory,
+    InterceptorFactoryTrait, InterceptorLinkWrapper, InterceptorTrait, InterfaceEnabled,
+    RoutingContext,
+};
+
+const DEFAULT_MAX_DELAY_MS: u64 = 1000;
+
+pub(crate) fn rate_limit_interceptor_factories(
+    config: &Vec<RateLimitConf>,
+) -> ZResult<Vec<InterceptorFactory>> {
+    let mut res: Vec<InterceptorFactory> = vec![];
+
+    validate_config(config).map_err(|e| format!("Invalid rate limit config: {e}"))?;
+    for conf in config {
+        res.push(Box::new(RateLimitInterceptorFactory::new(conf)));
+    }
+
+    Ok(res)
+}
+
+fn validate_config(config: &Vec<RateLimitConf>) -> ZResult<()> {
+    let mut id_set = HashSet::new();
+    for conf in config {
+        let name = conf.id.as_deref().unwrap_or("<unnamed>");
+        if let Some(id) = &conf.id {
+            if !id_set.insert(id.clone()) {
+                bail!("id '{id}' is repeated");
+            }
+        }
+        if conf.bytes_per_sec.is_none() && conf.msgs_per_sec.is_none() {
+            bail!("'{name}' requires at least one of `bytes_per_sec` or `msgs_per_sec`");
+        }
+        for (field, value) in [
+            ("bytes_per_sec", conf.bytes_per_sec),
+            ("bytes_burst", conf.bytes_burst),
+            ("msgs_per_sec", conf.msgs_per_sec),
+            ("msgs_burst", conf.msgs_burst),
+        ] {
+            if value == Some(0) {
+                bail!("'{name}' has a null `{field}`");
+            }
+        }
+        if conf.bytes_per_sec.is_none() && conf.bytes_burst.is_some() {
+            bail!("'{name}' has a `bytes_burst` without `bytes_per_sec`");
+        }
+        if conf.msgs_per_sec.is_none() && conf.msgs_burst.is_some() {
+            bail!("'{name}' has a `msgs_burst` without `msgs_per_sec`");
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default, Clone)]
+struct RateLimitFilters {
+    put: bool,
+    delete: bool,
+    query: bool,
+    reply: bool,
+}
+
+impl From<&NEVec<RateLimitMessage>> for RateLimitFilters {
+    fn from(value: &NEVec<RateLimitMessage>) -> Self {
+        let mut res = Self::default();
+        for v in value {
+            match v {
+                RateLimitMessage::Put => res.put = true,
+                RateLimitMessage::Delete => res.delete = true,
+                RateLimitMessage::Query => res.query = true,
+                RateLimitMessage::Reply => res.reply = true,
+            }
+        }
+        res
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Budget {
+    rate: f64,
+    burst: f64,
+}
+
+impl Budget {
+    fn new(rate: Option<u64>, burst: Option<u64>) -> Option<Self> {
+        rate.map(|rate| Self {
+            rate: rate as f64,
+            burst: burst.unwrap_or(rate) as f64,
+        })
+    }
+}
+
+pub struct RateLimitInterceptorFactory {
+    id: Option<String>,
+    interfaces: Option<NEVec<Interface>>,
+    cert_common_names: Option<NEVec<CertCommonName>>,
+    usernames: Option<NEVec<Username>>,
+    link_protocols: Option<NEVec<InterceptorLink>>,
+    flows: InterfaceEnabled,
+    messages: Arc<RateLimitFilters>,
+    key_exprs: NEVec<OwnedKeyExpr>,
+    bytes: Option<Budget>,
+    msgs: Option<Budget>,
+    policy: RateLimitPolicy,
+    max_delay: Duration,
+}
+
+impl RateLimitInterceptorFactory {
+    fn new(conf: &RateLimitConf) -> Self {
+        Self {
+            id: conf.id.clone(),
+            interfaces: conf.interfaces.clone(),
+            cert_common_names: conf.cert_common_names.clone(),
+            usernames: conf.usernames.clone(),
+            link_protocols: conf.link_protocols.clone(),
+            flows: conf
+                .flows
+                .as_ref()
+                .map(|f| f.into())
+                .unwrap_or(InterfaceEnabled {
+                    ingress: true,
+                    egress: true,
+                }),
+            messages: Arc::new((&conf.messages).into()),
+            key_exprs: conf.key_exprs.clone(),
+            bytes: Budget::new(conf.bytes_per_sec, conf.bytes_burst),
+            msgs: Budget::new(conf.msgs_per_sec, conf.msgs_burst),
+            policy: conf.policy.unwrap_or_default(),
+            max_delay: Duration::from_millis(conf.max_delay.unwrap_or(DEFAULT_MAX_DELAY_MS)),
+        }
+    }
+
+    /// Checks whether the transport matches the subject of this rate limit,
+    /// i.e. each of the configured subject properties matches one of the transport's.
+    fn matches(&self, transport: &TransportUnicast) -> ZResult<bool> {
+        if let Some(interfaces) = &self.interfaces {
+            if !transport
+                .get_links()?
+                .into_iter()
+                .flat_map(|link| link.interfaces)
+                .any(|i| interfaces.iter().any(|x| x.0 == i))
+            {
+                return Ok(false);
+            }
+        }
+        let auth_ids = transport.get_auth_ids()?;
+        if let Some(cert_common_names) = &self.cert_common_names {
+            if !auth_ids
+                .link_auth_ids()
+                .iter()
+                .any(|auth_id| match auth_id {
+                    LinkAuthId::Tls(Some(cn)) | LinkAuthId::Quic(Some(cn)) => {
+                        cert_common_names.iter().any(|x| &x.0 == cn)
+                    }
+                    _ => false,
+                })
+            {
+                return Ok(false);
+            }
+        }
+        if let Some(usernames) = &self.usernames {
+            if !auth_ids
+                .username()
+                .is_some_and(|u| usernames.iter().any(|x| &x.0 == u))
+            {
+                return Ok(false);
+            }
+        }
+        if let Some(link_protocols) = &self.link_protocols {
+            if !auth_ids
+                .link_auth_ids()
+                .iter()
+                .map(|auth_id| InterceptorLinkWrapper::from(auth_id).0)
+                .any(|v| link_protocols.contains(&v))
+            {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn interceptor(&self, flow: InterceptorFlow) -> ComputeOnMiss<RateLimitInterceptor> {
+        ComputeOnMiss::new(RateLimitInterceptor {
+            messages: self.messages.clone(),
+            limiters: self
+                .key_exprs
+                .iter()
+                .map(|key_expr| Limiter {
+                    key_expr: key_expr.clone(),
+                    bytes: self.bytes.map(|b| Mutex::new(TokenBucket::new(b))),
+                    msgs: self.msgs.map(|b| Mutex::new(TokenBucket::new(b))),
+                })
+                .collect(),
+            policy: self.policy,
+            max_delay: self.max_delay,
+            flow,
+        })
+    }
+}
+
+impl InterceptorFactoryTrait for RateLimitInterceptorFactory {
+    fn new_transport_unicast(
+        &self,
+        transport: &TransportUnicast,
+    ) -> (Option<IngressInterceptor>, Option<EgressInterceptor>) {
+        match self.matches(transport) {
+            Ok(true) => {}
+            Ok(false) => return (None, None),
+            Err(e) => {
+                tracing::error!("Error loading transport properties: {e}");
+                return (None, None);
+            }
+        }
+
+        tracing::debug!(
+            "New{}{} rate limiter {} on transport unicast {:?}",
+            self.flows.ingress.then_some(" ingress").unwrap_or_default(),
+            self.flows.egress.then_some(" egress").unwrap_or_default(),
+            self.id.as_deref().unwrap_or_default(),
+            transport
+        );
+        (
+            self.flows.ingress.then(|| {
+                Box::new(self.interceptor(InterceptorFlow::Ingress)) as IngressInterceptor
+            }),
+            self.flows
+                .egress
+                .then(|| Box::new(self.interceptor(InterceptorFlow::Egress)) as EgressInterceptor),
+        )
+    }
+
+    fn new_transport_multicast(
+        &self,
+        _transport: &TransportMulticast,
+    ) -> Option<EgressInterceptor> {
+        None
+    }
+
+    fn new_peer_multicast(&self, _transport: &TransportMulticast) -> Option<IngressInterceptor> {
+        None
+    }
+}
+
+/// A token bucket refilled at `rate` tokens per second up to `burst` tokens.
+///
+/// A request larger than the burst is granted when the bucket is full, putting
+/// the bucket in debt so that the sustained rate is still enforced.
+struct TokenBucket {
+    budget: Budget,
+    tokens: f64,
+    last: Instant,
+}
+
+impl TokenBucket {
+    fn new(budget: Budget) -> Self {
+        Self {
+            budget,
+            tokens: budget.burst,
+            last: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.budget.rate).min(self.budget.burst);
+        self.last = now;
+    }
+
+    /// Returns the time after which `amount` tokens will be available, `None` if they already are.
+    fn wait_time(&mut self, amount: f64, now: Instant) -> Option<Duration> {
+        self.refill(now);
+        let required = amount.min(self.budget.burst);
+        (self.tokens < required)
+            .then(|| Duration::from_secs_f64((required - self.tokens) / self.budget.rate))
+    }
+
+    fn consume(&mut self, amount: f64) {
+        self.tokens -= amount;
+    }
+}
+
+struct Limiter {
+    key_expr: OwnedKeyExpr,
+    bytes: Option<Mutex<TokenBucket>>,
+    msgs: Option<Mutex<TokenBucket>>,
+}
+
+impl Limiter {
+    /// Consumes the budgets of a message of `size` bytes if both are available,
+    /// otherwise returns the time after which they will be.
+    fn try_consume(&self, size: usize, now: Instant) -> Result<(), Duration> {
+        let mut bytes = self.bytes.as_ref().map(|b| zlock!(b));
+        let mut msgs = self.msgs.as_ref().map(|b| zlock!(b));
+        let bytes_wait = bytes.as_mut().and_then(|b| b.wait_time(size as f64, now));
+        let msgs_wait = msgs.as_mut().and_then(|b| b.wait_time(1.0, now));
+        if let Some(wait) = bytes_wait.max(msgs_wait) {
+            return Err(wait);
+        }
+        if let Some(b) = bytes.as_mut() {
+            b.consume(size as f64);
+        }
+        if let Some(b) = msgs.as_mut() {
+            b.consume(1.0);
+        }
+        Ok(())
+    }
+}
+
+// The flag is used to print a message only once
+static INFO_FLAG: AtomicBool = AtomicBool::new(false);
+
+pub(crate) struct RateLimitInterceptor {
+    messages: Arc<RateLimitFilters>,
+    limiters: Vec<Limiter>,
+    policy: RateLimitPolicy,
+    max_delay: Duration,
+    flow: InterceptorFlow,
+}
+
+impl RateLimitInterceptor {
+    /// Returns the serialized payload and attachment size of the rate limited messages.
+    fn message_size(&self, msg: &NetworkMessageMut) -> Option<usize> {
+        match &msg.body {
+            NetworkBodyMut::Push(Push { payload, .. }) => self.push_size(payload, false),
+            NetworkBodyMut::Response(Response {
+                payload: ResponseBody::Reply(Reply { payload, .. }),
+                ..
+            }) => self.push_size(payload, true),
+            NetworkBodyMut::Response(Response {
+                payload: ResponseBody::Err(err),
+                ..
+            }) => self.messages.reply.then(|| err.payload.len()),
+            NetworkBodyMut::Request(Request {
+                payload: RequestBody::Query(query),
+                ..
+            }) => self.messages.query.then(|| {
+                query
+                    .ext_body
+                    .as_ref()
+                    .map(|body| body.payload.len())
+                    .unwrap_or(0)
+                    + query
+                        .ext_attachment
+                        .as_ref()
+                        .map(|att| att.buffer.len())
+                        .unwrap_or(0)
+            }),
+            NetworkBodyMut::ResponseFinal(_)
+            | NetworkBodyMut::Interest(_)
+            | NetworkBodyMut::Declare(_)
+            | NetworkBodyMut::OAM(_) => None,
+        }
+    }
+
+    fn push_size(&self, payload: &PushBody, reply: bool) -> Option<usize> {
+        match payload {
+            PushBody::Put(put) => (if reply {
+                self.messages.reply
+            } else {
+                self.messages.put
+            })
+            .then(|| {
+                put.payload.len()
+                    + put
+                        .ext_attachment
+                        .as_ref()
+                        .map(|att| att.buffer.len())
+                        .unwrap_or(0)
+            }),
+            PushBody::Del(del) => (if reply {
+                self.messages.reply
+            } else {
+                self.messages.delete
+            })
+            .then(|| {
+                del.ext_attachment
+                    .as_ref()
+                    .map(|att| att.buffer.len())
+                    .unwrap_or(0)
+            }),
+        }
+    }
+
+    fn acquire(&self, limiter: &Limiter, size: usize) -> bool {
+        let mut delayed = Duration::ZERO;
+        loop {
+            match limiter.try_consume(size, Instant::now()) {
+                Ok(()) => return true,
+                Err(wait) => match self.policy {
+                    RateLimitPolicy::Drop => return false,
+                    RateLimitPolicy::Delay => {
+                        if delayed + wait > self.max_delay {
+                            return false;
+                        }
+                        std::thread::sleep(wait);
+                        delayed += wait;
+                    }
+                },
+            }
+        }
+    }
+}
+
+impl InterceptorTrait for RateLimitInterceptor {
+    fn compute_keyexpr_cache(&self, key_expr: &keyexpr) -> Option<Box<dyn Any + Send + Sync>> {
+        Some(Box::new(
+            self.limiters
+                .iter()
+                .enumerate()
+                .filter_map(|(id, l)| l.key_expr.intersects(key_expr).then_some(id))
+                .collect::<Vec<usize>>(),
+        ))
+    }
+
+    fn intercept(
+        &self,
+        ctx: &mut RoutingContext<NetworkMessageMut>,
+        cache: Option<&Box<dyn Any + Send + Sync>>,
+    ) -> bool {
+        let Some(size) = self.message_size(&ctx.msg) else {
+            return true;
+        };
+        let Some(ids) = cache.and_then(|c| c.downcast_ref::<Vec<usize>>()) else {
+            tracing::debug!("unexpected cache type {:?}", ctx.full_expr());
+            return true;
+        };
+        for id in ids {
+            if !self.acquire(&self.limiters[*id], size) {
+                if !INFO_FLAG.swap(true, Ordering::Relaxed) {
+                    tracing::info!("Some message(s) have been dropped by the rate limit interceptor. Enable trace level tracing for more details.");
+                }
+                tracing::trace!(
+                    "Message dropped by the {:?} rate limit interceptor: {}({}) from:{} to:{}",
+                    self.flow,
+                    ctx.msg,
+                    ctx.full_expr().unwrap_or_default(),
+                    ctx.inface().map(|f| f.to_string()).unwrap_or_default(),
+                    ctx.outface().map(|f| f.to_string()).unwrap_or_default(),
+                );
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limit_token_bucket() {
+        let mut bucket = TokenBucket::new(Budget {
+            rate: 10.0,
+            burst: 20.0,
+        });
+        let now = bucket.last;
+        assert_eq!(bucket.wait_time(20.0, now), None);
+        bucket.consume(15.0);
+        assert_eq!(
+            bucket.wait_time(10.0, now),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(
+            bucket.wait_time(10.0, now + Duration::from_millis(500)),
+            None
+        );
+        // requests larger than the burst are granted on a full bucket, leaving it in debt
+        let now = now + Duration::from_secs(10);
+        assert_eq!(bucket.wait_time(50.0, now), None);
+        bucket.consume(50.0);
+        assert_eq!(
+            bucket.wait_time(1.0, now),
+            Some(Duration::from_millis(3100))
+        );
+    }
+}
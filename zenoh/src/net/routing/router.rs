@@ -129,7 +129,12 @@ impl Router {
         let stats = transport.get_stats()?;
 
         let ingress = Arc::new(ArcSwap::new(InterceptorsChain::empty().into()));
-        let mux = Arc::new(Mux::new(transport.clone(), InterceptorsChain::empty()));
+        let mux = Arc::new(Mux::new(
+            transport.clone(),
+            InterceptorsChain::empty(),
+            #[cfg(feature = "stats")]
+            tables.keyexpr_stats.clone(),
+        ));
         let newface = tables
             .faces
             .entry(fid)
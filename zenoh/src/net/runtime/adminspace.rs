@@ -216,6 +216,13 @@ impl AdminSpace {
                 Arc::new(route_successor),
             );
         }
+        #[cfg(feature = "stats")]
+        handlers.insert(
+            format!("@/{zid_str}/{whatami_str}/stats/keyexpr/**")
+                .try_into()
+                .unwrap(),
+            Arc::new(keyexpr_stats_data),
+        );
 
         #[cfg(feature = "plugins")]
         handlers.insert(
@@ -494,6 +501,8 @@ impl Primitives for AdminSpace {
                     value: mem::take(&mut query.ext_body)
                         .map(|b| (b.payload.into(), b.encoding.into())),
                     attachment: query.ext_attachment.take().map(Into::into),
+                    #[cfg(feature = "trace-context")]
+                    trace_context: msg.ext_trace.take().map(Into::into),
                 };
 
                 for (key, handler) in &self.handlers {
@@ -695,6 +704,17 @@ zenoh_build{{version="{}"}} 1
             .openmetrics_text(),
     );
 
+    #[cfg(feature = "stats")]
+    metrics.push_str(
+        &zread!(context.runtime.state.router.tables.tables)
+            .keyexpr_stats
+            .openmetrics_text(),
+    );
+
+    metrics.push_str(&routing_metrics(context));
+    #[cfg(feature = "plugins")]
+    metrics.push_str(&plugins_metrics(context));
+
     if let Err(e) = query
         .reply(reply_key, metrics)
         .encoding(Encoding::TEXT_PLAIN)
@@ -704,6 +724,82 @@ zenoh_build{{version="{}"}} 1
     }
 }
 
+fn routing_metrics(context: &AdminContext) -> String {
+    let tables = &context.runtime.state.router.tables;
+    let rtables = zread!(tables.tables);
+    let gauges = [
+        (
+            "zenoh_routing_faces",
+            "Number of faces in the routing tables.",
+            rtables.faces.len(),
+        ),
+        (
+            "zenoh_routing_subscriptions",
+            "Number of subscriptions in the routing tables.",
+            tables.hat_code.get_subscriptions(&rtables).len(),
+        ),
+        (
+            "zenoh_routing_publications",
+            "Number of publications in the routing tables.",
+            tables.hat_code.get_publications(&rtables).len(),
+        ),
+        (
+            "zenoh_routing_queryables",
+            "Number of queryables in the routing tables.",
+            tables.hat_code.get_queryables(&rtables).len(),
+        ),
+        (
+            "zenoh_routing_queriers",
+            "Number of queriers in the routing tables.",
+            tables.hat_code.get_queriers(&rtables).len(),
+        ),
+    ];
+    drop(rtables);
+
+    let mut metrics = String::new();
+    for (name, help, value) in gauges {
+        metrics.push_str(&format!(
+            "# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n"
+        ));
+    }
+    metrics
+}
+
+#[cfg(feature = "plugins")]
+fn plugins_metrics(context: &AdminContext) -> String {
+    let statuses = context
+        .runtime
+        .plugins_manager()
+        .plugins_status(unsafe { keyexpr::from_str_unchecked("**") })
+        .into_iter()
+        .map(|status| status.into_owned())
+        .collect_vec();
+
+    let mut metrics = String::from(
+        "# HELP zenoh_plugin_state State of the plugins and their sub-plugins (0: declared, 1: loaded, 2: started).\n\
+         # TYPE zenoh_plugin_state gauge\n",
+    );
+    for status in &statuses {
+        metrics.push_str(&format!(
+            "zenoh_plugin_state{{plugin=\"{}\"}} {}\n",
+            status.name(),
+            status.state() as u8
+        ));
+    }
+    metrics.push_str(
+        "# HELP zenoh_plugin_report_level Severity of the plugins reports (0: info, 1: warning, 2: error).\n\
+         # TYPE zenoh_plugin_report_level gauge\n",
+    );
+    for status in &statuses {
+        metrics.push_str(&format!(
+            "zenoh_plugin_report_level{{plugin=\"{}\"}} {}\n",
+            status.name(),
+            status.report().get_level() as u8
+        ));
+    }
+    metrics
+}
+
 fn routers_linkstate_data(context: &AdminContext, query: Query) {
     let reply_key: OwnedKeyExpr = format!(
         "@/{}/{}/linkstate/routers",
@@ -769,6 +865,29 @@ fn subscribers_data(context: &AdminContext, query: Query) {
     }
 }
 
+#[cfg(feature = "stats")]
+fn keyexpr_stats_data(context: &AdminContext, query: Query) {
+    let keyexpr_stats = zread!(context.runtime.state.router.tables.tables)
+        .keyexpr_stats
+        .clone();
+    for (key_expr, report) in keyexpr_stats.report() {
+        let key = KeyExpr::try_from(format!(
+            "@/{}/{}/stats/keyexpr/{}",
+            context.runtime.state.zid, context.runtime.state.whatami, key_expr
+        ))
+        .unwrap();
+        if query.key_expr().intersects(&key) {
+            if let Err(e) = query
+                .reply(key, ZBytes::from(report.to_string()))
+                .encoding(Encoding::APPLICATION_JSON)
+                .wait()
+            {
+                tracing::error!("Error sending AdminSpace reply: {:?}", e);
+            }
+        }
+    }
+}
+
 fn publishers_data(context: &AdminContext, query: Query) {
     let tables = &context.runtime.state.router.tables;
     let rtables = zread!(tables.tables);
@@ -742,6 +742,7 @@ fn client_test() {
                 ext_qos: ext::QoSType::DEFAULT,
                 ext_tstamp: None,
                 ext_nodeid: ext::NodeIdType { node_id: 0 },
+                ext_trace: None,
                 payload: PushBody::Put(Put {
                     timestamp: None,
                     encoding: Encoding::empty(),
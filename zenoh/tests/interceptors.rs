@@ -501,3 +501,63 @@ fn downsampling_reply_test() {
     downsampling_reply_rate_test(InterceptorFlow::Ingress);
     downsampling_reply_rate_test(InterceptorFlow::Egress);
 }
+
+#[cfg(feature = "stats")]
+#[test]
+fn downsampling_keyexpr_stats() {
+    zenoh::init_log_from_env_or("error");
+    let ke_prefix = "test/downsampling_keyexpr_stats";
+    let locator = "tcp/127.0.0.1:31450";
+    let ke_downsampled = format!("{ke_prefix}/downsampled");
+    let ke_stats = format!("{ke_prefix}/**");
+
+    let ds_config = DownsamplingItemConf {
+        id: None,
+        flows: Some(nev![InterceptorFlow::Ingress]),
+        interfaces: None,
+        link_protocols: None,
+        messages: nev![DownsamplingMessage::Push],
+        rules: nev![DownsamplingRuleConf {
+            key_expr: ke_downsampled.as_str().try_into().unwrap(),
+            freq: 0.01,
+        }],
+    };
+    let (pub_config, mut sub_config) =
+        build_config(locator, vec![ds_config], InterceptorFlow::Ingress);
+    sub_config.adminspace.set_enabled(true).unwrap();
+    sub_config
+        .stats
+        .set_key_exprs(vec![ke_stats.as_str().try_into().unwrap()])
+        .unwrap();
+
+    let sub_session = zenoh::open(sub_config).wait().unwrap();
+    let _sub = sub_session
+        .declare_subscriber(&ke_stats)
+        .callback(|_| {})
+        .wait()
+        .unwrap();
+    let pub_session = zenoh::open(pub_config).wait().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(WARMUP_MS));
+
+    for _ in 0..10 {
+        pub_session.put(&ke_downsampled, "message").wait().unwrap();
+        pub_session
+            .put(format!("{ke_prefix}/free"), "message")
+            .wait()
+            .unwrap();
+    }
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    let replies = sub_session
+        .get(format!("@/{}/*/stats/keyexpr/**", sub_session.zid()))
+        .wait()
+        .unwrap();
+    let reply = replies.recv().unwrap();
+    let sample = reply.result().unwrap();
+    assert!(sample.key_expr().as_str().ends_with(&ke_stats));
+    let report: serde_json::Value = serde_json::from_slice(&sample.payload().to_bytes()).unwrap();
+    assert_eq!(report["rx_msgs"], 11);
+    assert_eq!(report["rx_bytes"], 11 * "message".len());
+    assert_eq!(report["rx_downsampler_dropped_msgs"], 9);
+    assert_eq!(report["rx_acl_denied_msgs"], 0);
+}
@@ -0,0 +1,196 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+#![cfg(feature = "internal_config")]
+#![cfg(unix)]
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use nonempty_collections::nev;
+use zenoh::{Config, Wait};
+use zenoh_config::{InterceptorFlow, RateLimitConf, RateLimitMessage, RateLimitPolicy, Username};
+
+static KE_LIMITED: &str = "test/rate_limit/limited";
+static KE_FREE: &str = "test/rate_limit/free";
+static NB_MSGS: usize = 50;
+static DECLARATION_DELAY_MS: u64 = 250;
+static MESSAGES_DELAY_MS: u64 = 1000;
+
+fn rate_limit_conf(flow: InterceptorFlow) -> RateLimitConf {
+    RateLimitConf {
+        id: None,
+        interfaces: None,
+        cert_common_names: None,
+        usernames: None,
+        link_protocols: None,
+        flows: Some(nev![flow]),
+        messages: nev![RateLimitMessage::Put],
+        key_exprs: nev![KE_LIMITED.try_into().unwrap()],
+        bytes_per_sec: None,
+        bytes_burst: None,
+        msgs_per_sec: Some(10),
+        msgs_burst: None,
+        policy: None,
+        max_delay: None,
+    }
+}
+
+fn build_config(locator: &str, rate_limit: RateLimitConf) -> (Config, Config) {
+    let mut pub_config = Config::default();
+    pub_config
+        .scouting
+        .multicast
+        .set_enabled(Some(false))
+        .unwrap();
+    pub_config
+        .connect
+        .endpoints
+        .set(vec![locator.parse().unwrap()])
+        .unwrap();
+
+    let mut sub_config = Config::default();
+    sub_config
+        .scouting
+        .multicast
+        .set_enabled(Some(false))
+        .unwrap();
+    sub_config
+        .listen
+        .endpoints
+        .set(vec![locator.parse().unwrap()])
+        .unwrap();
+
+    match rate_limit.flows.as_ref().map(|f| *f.first()) {
+        Some(InterceptorFlow::Egress) => pub_config.set_rate_limit(vec![rate_limit]).unwrap(),
+        _ => sub_config.set_rate_limit(vec![rate_limit]).unwrap(),
+    };
+    (pub_config, sub_config)
+}
+
+/// Publishes `NB_MSGS` messages on both the limited and free key expressions
+/// and returns the number of received messages along with the publication duration.
+fn rate_limit_pub_sub_test(locator: &str, rate_limit: RateLimitConf) -> (usize, usize, Duration) {
+    let (pub_config, sub_config) = build_config(locator, rate_limit);
+    let sub_session = zenoh::open(sub_config).wait().unwrap();
+    let pub_session = zenoh::open(pub_config).wait().unwrap();
+
+    let limited = Arc::new(AtomicUsize::new(0));
+    let free = Arc::new(AtomicUsize::new(0));
+    let _sub = sub_session
+        .declare_subscriber("test/rate_limit/*")
+        .callback({
+            let limited = limited.clone();
+            let free = free.clone();
+            move |sample| {
+                let counter = if sample.key_expr().as_str() == KE_LIMITED {
+                    &limited
+                } else {
+                    &free
+                };
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .wait()
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(DECLARATION_DELAY_MS));
+
+    let start = Instant::now();
+    for _ in 0..NB_MSGS {
+        pub_session.put(KE_LIMITED, "message").wait().unwrap();
+        pub_session.put(KE_FREE, "message").wait().unwrap();
+    }
+    let elapsed = start.elapsed();
+    std::thread::sleep(Duration::from_millis(MESSAGES_DELAY_MS));
+
+    pub_session.close().wait().unwrap();
+    sub_session.close().wait().unwrap();
+    (
+        limited.load(Ordering::SeqCst),
+        free.load(Ordering::SeqCst),
+        elapsed,
+    )
+}
+
+#[test]
+fn rate_limit_drop_test() {
+    zenoh::init_log_from_env_or("error");
+    for (flow, locator) in [
+        (InterceptorFlow::Ingress, "tcp/127.0.0.1:31460"),
+        (InterceptorFlow::Egress, "tcp/127.0.0.1:31461"),
+    ] {
+        let (limited, free, elapsed) = rate_limit_pub_sub_test(locator, rate_limit_conf(flow));
+        let refilled = (elapsed.as_secs_f64() * 10.0).ceil() as usize;
+        assert!(
+            (10..=10 + refilled).contains(&limited),
+            "{flow:?}: received {limited} limited messages in {elapsed:?}"
+        );
+        assert_eq!(free, NB_MSGS);
+    }
+}
+
+#[test]
+fn rate_limit_bytes_test() {
+    zenoh::init_log_from_env_or("error");
+    let mut conf = rate_limit_conf(InterceptorFlow::Ingress);
+    conf.msgs_per_sec = None;
+    conf.bytes_per_sec = Some(7);
+    conf.bytes_burst = Some(7 * 5);
+    let (limited, free, elapsed) = rate_limit_pub_sub_test("tcp/127.0.0.1:31462", conf);
+    let refilled = elapsed.as_secs_f64().ceil() as usize;
+    assert!(
+        (5..=5 + refilled).contains(&limited),
+        "received {limited} limited messages in {elapsed:?}"
+    );
+    assert_eq!(free, NB_MSGS);
+}
+
+#[test]
+fn rate_limit_delay_test() {
+    zenoh::init_log_from_env_or("error");
+    let mut conf = rate_limit_conf(InterceptorFlow::Egress);
+    conf.msgs_per_sec = Some(100);
+    conf.msgs_burst = Some(1);
+    conf.policy = Some(RateLimitPolicy::Delay);
+    let (limited, free, elapsed) = rate_limit_pub_sub_test("tcp/127.0.0.1:31463", conf);
+    assert_eq!(limited, NB_MSGS);
+    assert_eq!(free, NB_MSGS);
+    // The first message is granted by the burst
+    assert!(elapsed >= Duration::from_millis(10 * (NB_MSGS as u64 - 1)));
+}
+
+#[test]
+fn rate_limit_subject_test() {
+    zenoh::init_log_from_env_or("error");
+    let mut conf = rate_limit_conf(InterceptorFlow::Ingress);
+    conf.usernames = Some(nev![Username("unknown".to_string())]);
+    let (limited, free, _) = rate_limit_pub_sub_test("tcp/127.0.0.1:31464", conf);
+    assert_eq!(limited, NB_MSGS);
+    assert_eq!(free, NB_MSGS);
+}
+
+#[test]
+fn rate_limit_config_error_test() {
+    zenoh::init_log_from_env_or("error");
+    let mut conf = rate_limit_conf(InterceptorFlow::Ingress);
+    conf.msgs_per_sec = None;
+    let mut config = Config::default();
+    config.set_rate_limit(vec![conf]).unwrap();
+    assert!(zenoh::open(config).wait().is_err());
+}
@@ -0,0 +1,114 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+#![cfg(feature = "trace-context")]
+use std::time::Duration;
+
+use opentelemetry::trace::noop::NoopTracer;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::layer::SubscriberExt;
+use zenoh::{config::Config, sample::TraceContext, Wait};
+use zenoh_core::ztimeout;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+const SLEEP: Duration = Duration::from_secs(1);
+
+const KEY_EXPR: &str = "test/trace_context";
+const TRACESTATE: &str = "congo=t61rcWkgMzE";
+
+// A span whose OpenTelemetry parent is the given trace context
+fn span(trace_context: &TraceContext) -> tracing::Span {
+    let span = tracing::info_span!("trace_context");
+    span.set_parent(trace_context.context());
+    span
+}
+
+fn peer_config(listen: Option<&str>, connect: Option<&str>) -> Config {
+    let mut config = Config::default();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    if let Some(listen) = listen {
+        config
+            .insert_json5("listen/endpoints", &format!(r#"["{listen}"]"#))
+            .unwrap();
+    }
+    if let Some(connect) = connect {
+        config
+            .insert_json5("connect/endpoints", &format!(r#"["{connect}"]"#))
+            .unwrap();
+    }
+    config
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn trace_context_end_to_end() {
+    // The no-op tracer uses the remote parent of a span as its context,
+    // so the propagated trace context is exactly the one of the parent.
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(NoopTracer::new()));
+    tracing::subscriber::set_global_default(subscriber).unwrap();
+
+    let put_context = TraceContext::new(
+        "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+        TRACESTATE,
+    )
+    .unwrap();
+    let get_context = TraceContext::new(
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        TRACESTATE,
+    )
+    .unwrap();
+    let reply_context = TraceContext::new(
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-53995c3f42cd8ad8-00",
+        "",
+    )
+    .unwrap();
+
+    let endpoint = "tcp/127.0.0.1:27460";
+    let session1 = ztimeout!(zenoh::open(peer_config(Some(endpoint), None))).unwrap();
+    let session2 = ztimeout!(zenoh::open(peer_config(None, Some(endpoint)))).unwrap();
+
+    let subscriber = ztimeout!(session2.declare_subscriber(KEY_EXPR)).unwrap();
+    let queryable = ztimeout!(session2.declare_queryable(KEY_EXPR)).unwrap();
+    tokio::time::sleep(SLEEP).await;
+
+    // Put
+    span(&put_context)
+        .in_scope(|| session1.put(KEY_EXPR, "put").wait())
+        .unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert_eq!(sample.trace_context(), Some(&put_context));
+
+    // A put outside of any traced span has no trace context
+    ztimeout!(session1.put(KEY_EXPR, "put")).unwrap();
+    let sample = ztimeout!(subscriber.recv_async()).unwrap();
+    assert_eq!(sample.trace_context(), None);
+
+    // Get and reply
+    let replies = span(&get_context)
+        .in_scope(|| session1.get(KEY_EXPR).wait())
+        .unwrap();
+    let query = ztimeout!(queryable.recv_async()).unwrap();
+    assert_eq!(query.trace_context(), Some(&get_context));
+    span(&reply_context)
+        .in_scope(|| query.reply(KEY_EXPR, "reply").wait())
+        .unwrap();
+    drop(query);
+    let reply = ztimeout!(replies.recv_async()).unwrap();
+    assert!(reply.result().is_ok());
+    assert_eq!(reply.trace_context(), Some(&reply_context));
+
+    ztimeout!(session1.close()).unwrap();
+    ztimeout!(session2.close()).unwrap();
+}
@@ -18,6 +18,8 @@ use zenoh::{config::WhatAmI, Config, Result, Wait};
 use zenoh_config::{EndPoint, ModeDependentValue, PermissionsConf};
 use zenoh_util::LibSearchDirs;
 
+mod metrics;
+
 const GIT_VERSION: &str = git_version!(prefix = "v", cargo_prefix = "v");
 
 lazy_static::lazy_static!(
@@ -61,6 +63,12 @@ struct Args {
     ///   - `none` to disable the REST API
     #[arg(long, value_name = "SOCKET")]
     rest_http_port: Option<String>,
+    /// Configures an HTTP server exposing the router metrics on `/metrics` in the OpenMetrics text format
+    /// (e.g. for Prometheus scraping), overriding `adminspace/metrics_http_port`. Disabled by default. Accepted values:
+    ///   - a port number
+    ///   - a string with format `<local_ip>:<port_number>` (to bind the HTTP server to a specific interface)
+    #[arg(long, value_name = "SOCKET")]
+    metrics_http_port: Option<String>,
     /// Allows arbitrary configuration changes as column-separated KEY:VALUE pairs,
     /// where the empty key is used to represent the entire configuration:
     ///   - KEY must be a valid config path, or empty string if the whole configuration is defined.
@@ -88,8 +96,18 @@ fn main() {
     let args = Args::parse();
     let config = config_from_args(&args);
     tracing::info!("Initial conf: {}", &config);
+    let metrics_socket = config
+        .adminspace
+        .metrics_http_port()
+        .as_deref()
+        .map(|value| {
+            metrics::parse_socket(value).unwrap_or_else(|e| {
+                eprintln!("{e}. Exiting...");
+                std::process::exit(-1);
+            })
+        });
 
-    let _session = match zenoh::open(config).wait() {
+    let session = match zenoh::open(config).wait() {
         Ok(runtime) => runtime,
         Err(e) => {
             eprintln!("{e}. Exiting...");
@@ -97,6 +115,13 @@ fn main() {
         }
     };
 
+    if let Some(socket) = metrics_socket {
+        if let Err(e) = metrics::serve(session.clone(), socket) {
+            eprintln!("Failed to serve metrics on {socket}: {e}. Exiting...");
+            std::process::exit(-1);
+        }
+    }
+
     std::thread::park();
 }
 
@@ -134,6 +159,12 @@ fn config_from_args(args: &Args) -> Config {
                 .unwrap();
         }
     }
+    if let Some(value) = &args.metrics_http_port {
+        config
+            .adminspace
+            .set_metrics_http_port(Some(value.clone()))
+            .unwrap();
+    }
     config.adminspace.set_enabled(true).unwrap();
     config.plugins_loading.set_enabled(true).unwrap();
     if !args.plugin_search_dir.is_empty() {
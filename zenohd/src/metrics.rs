@@ -0,0 +1,173 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+//! A minimal HTTP server exposing the `@/<zid>/<whatami>/metrics` admin space entry
+//! in the OpenMetrics text format, so that Prometheus can scrape zenohd directly.
+use std::{io, net::SocketAddr, time::Duration};
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+};
+use zenoh::{internal::runtime::ZRuntime, Session};
+
+const METRICS_PATH: &str = "/metrics";
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Parses the value of the `adminspace/metrics_http_port` configuration: either a port number
+/// (the server then binds on all interfaces) or a `<local_ip>:<port_number>` string.
+pub(crate) fn parse_socket(value: &str) -> Result<SocketAddr, String> {
+    match value.parse::<u16>() {
+        Ok(port) => Ok(SocketAddr::from(([0, 0, 0, 0], port))),
+        Err(_) => value
+            .parse::<SocketAddr>()
+            .map_err(|e| format!("Invalid metrics HTTP socket `{value}`: {e}")),
+    }
+}
+
+/// Binds the metrics HTTP server on `addr` and serves it on the zenoh runtime.
+pub(crate) fn serve(session: Session, addr: SocketAddr) -> io::Result<SocketAddr> {
+    let listener = ZRuntime::Application.block_in_place(TcpListener::bind(addr))?;
+    let local_addr = listener.local_addr()?;
+    ZRuntime::Application.spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let session = session.clone();
+                    ZRuntime::Application.spawn(async move {
+                        if let Err(e) = handle_connection(&session, stream).await {
+                            tracing::debug!("Error serving metrics request: {}", e);
+                        }
+                    });
+                }
+                Err(e) => tracing::warn!("Error accepting metrics connection: {}", e),
+            }
+        }
+    });
+    tracing::info!("Serving metrics on http://{}{}", local_addr, METRICS_PATH);
+    Ok(local_addr)
+}
+
+async fn read_request_line(stream: &mut TcpStream) -> io::Result<String> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    // Headers are irrelevant, only consume them until the empty line
+    let mut header = String::new();
+    while reader.read_line(&mut header).await? > 2 {
+        header.clear();
+    }
+    Ok(request_line)
+}
+
+async fn handle_connection(session: &Session, mut stream: TcpStream) -> io::Result<()> {
+    let request_line = tokio::time::timeout(IO_TIMEOUT, read_request_line(&mut stream)).await??;
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts
+        .next()
+        .and_then(|target| target.split('?').next())
+        .unwrap_or_default();
+    let (status, body) = match (method, path) {
+        ("GET", METRICS_PATH) => match scrape(session).await {
+            Ok(metrics) => ("200 OK", metrics),
+            Err(e) => ("500 Internal Server Error", format!("{e}\n")),
+        },
+        ("GET", _) => (
+            "404 Not Found",
+            format!("Metrics are served on {METRICS_PATH}\n"),
+        ),
+        _ => ("405 Method Not Allowed", String::new()),
+    };
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {CONTENT_TYPE}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    tokio::time::timeout(IO_TIMEOUT, async {
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    })
+    .await?
+}
+
+async fn scrape(session: &Session) -> zenoh::Result<String> {
+    let replies = session
+        .get(format!("@/{}/*/metrics", session.zid()))
+        .await?;
+    let mut metrics = String::new();
+    while let Ok(reply) = replies.recv_async().await {
+        match reply.result() {
+            Ok(sample) => metrics.push_str(&sample.payload().try_to_string()?),
+            Err(e) => tracing::warn!(
+                "Error retrieving metrics from the admin space: {}",
+                e.payload().try_to_string().unwrap_or_default()
+            ),
+        }
+    }
+    if metrics.is_empty() {
+        zenoh::internal::bail!("No metrics available: check the admin space read permissions");
+    }
+    Ok(metrics)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+    };
+
+    use zenoh::{config::WhatAmI, Config, Wait};
+
+    use super::*;
+
+    #[test]
+    fn metrics_parse_socket() {
+        assert_eq!(
+            parse_socket("9090").unwrap(),
+            SocketAddr::from(([0, 0, 0, 0], 9090))
+        );
+        assert_eq!(
+            parse_socket("127.0.0.1:9090").unwrap(),
+            SocketAddr::from(([127, 0, 0, 1], 9090))
+        );
+        assert!(parse_socket("localhost").is_err());
+    }
+
+    #[test]
+    fn metrics_http_scrape() {
+        let mut config = Config::default();
+        config.set_mode(Some(WhatAmI::Router)).unwrap();
+        config.listen.endpoints.set(vec![]).unwrap();
+        config.scouting.multicast.set_enabled(Some(false)).unwrap();
+        config.adminspace.set_enabled(true).unwrap();
+        let session = zenoh::open(config).wait().unwrap();
+        let addr = serve(session, parse_socket("127.0.0.1:0").unwrap()).unwrap();
+
+        let get = |path: &str| {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            write!(stream, "GET {path} HTTP/1.1\r\nHost: {addr}\r\n\r\n").unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+
+        let response = get("/metrics");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("zenoh_build{version="));
+        assert!(response.contains("\nzenoh_routing_faces "));
+        assert!(get("/").starts_with("HTTP/1.1 404 Not Found\r\n"));
+    }
+}